@@ -0,0 +1,17 @@
+//! # Chat commands
+//!
+//! Opt-in framework for chat commands like `/veh infernus`,
+//! messages of players are parsed from the `chat:message` client event (used by the default alt:V chat resource)
+//! only after the first command is [added](fn.add.html).
+//!
+//! # Arguments
+//! Arguments are separated by spaces, double quotes can be used to pass argument with spaces: `/kick 1 "some reason"`.
+//!
+//! [`PlayerContainer`](../type.PlayerContainer.html) argument can be specified by id or part of the name,
+//! see [`CommandContext::arg`](struct.CommandContext.html#method.arg).
+//!
+//! # Permissions
+//! [Documentation is here](trait.CommandPermissions.html).
+//!
+
+pub use core_resource::exports::commands::*;
@@ -0,0 +1,27 @@
+//! # Components
+//!
+//! Typed data attached to base objects (`Job` of player, `Fuel` of vehicle, etc.),
+//! which can be queried like in ECS: [`query`](fn.query.html) returns base objects which have all requested components.
+//!
+//! Components are stored per base object and removed automatically when base object is removed or destroyed.
+//!
+//! # Examples
+//! ```rust
+//! struct Fuel(f32);
+//!
+//! altv::components::insert(&vehicle, Fuel(100.0))?;
+//!
+//! altv::set_interval(
+//!     || {
+//!         for (vehicle, mut fuel) in &altv::components::query::<(altv::VehicleContainer, &mut Fuel)>() {
+//!             if vehicle.is_engine_on()? {
+//!                 fuel.0 -= 0.1;
+//!             }
+//!         }
+//!         Ok(())
+//!     },
+//!     1000,
+//! );
+//! ```
+
+pub use core_resource::exports::components::*;
@@ -31,26 +31,61 @@ pub use exports::{
     VoiceChannel,
     Marker,
     Checkpoint,
+    AnyBaseObject,
     AnyEntity,
+    AnyWorldObject,
+    BaseObjectTypeSet,
+    Handle,
+    HandleType,
+    Weak,
+    WeakPlayer,
+    WeakVehicle,
+    WeakPed,
+    WeakNetworkObject,
+    WeakColShape,
+    WeakCheckpoint,
+    WeakBlip,
+    WeakMarker,
+    WeakVirtualEntity,
+    WeakVirtualEntityGroup,
+    WeakVoiceChannel,
 
     AttachToEntityBoneIndex,
     AttachToEntityBoneName,
     PlayerDateTime,
     PlayerHeadBlendData,
+    PlayerHeadOverlay,
+    PlayerDlcCloth,
+    PlayerDlcProp,
+    PlayerAppearance,
+    Loadout,
+    LoadoutWeapon,
+    weapon_name,
     PlayAnimation,
     AnimationFlags,
     anyhow,
+    serde_json,
     hash,
     Hash,
     VehicleModelInfo,
+    VehicleSnapshot,
+    VehicleMod,
+    Tuning,
+    TuningMod,
+    VehicleNeon,
     PedModelInfo,
     RGBA,
+    IntoRGBA,
+    Hsv,
+    Hsl,
     Quaternion,
     core_funcs::*,
     config_node::*,
     Resource,
     ColShapy,
     IntoVoidResult,
+    SomeResult,
+    VoidResult,
 };
 
 pub use altv_sdk::{
@@ -61,9 +96,12 @@ pub use altv_sdk::{
 pub const DEFAULT_DIMENSION: i32 = 0;
 pub const GLOBAL_DIMENSION: i32 = i32::MIN;
 
+pub mod commands;
+pub mod components;
 pub mod events;
 pub mod meta;
 pub mod mvalue;
+pub mod spatial;
 
 pub mod prelude {
     pub use super::exports::{
@@ -138,12 +176,24 @@ pub fn set_interval<V: IntoVoidResult>(mut callback: impl FnMut() -> V + 'static
     );
 }
 
-pub use resource_main_macro::resource_main_func as main;
-// __internal is intended for resource_main_func proc macro ^
+pub use resource_main_macro::{
+    client_event_handler as on_client, command_handler as command, event_handler as on,
+    resource_main_func as main, resource_stop_func as stop,
+};
+// __internal is intended for resource_main_macro and mvalue_derive proc macros ^
 #[doc(hidden)]
 pub mod __internal {
-    pub use super::exports::{init as core_init, ModuleHandlers, ResourceHandlers, ResourceName};
+    pub use super::exports::{
+        anyhow, init as core_init,
+        mvalue::{__internal::Serializable, FromMValue, MValue},
+        set_stop_hook, ModuleHandlers, ResourceHandlers, ResourceName, SomeResult,
+    };
     pub use altv_sdk::ffi::{alt::ICore, set_alt_core};
+    pub use linkme;
+
+    /// Handlers registered by `#[altv::on]`, `#[altv::on_client]`, `#[altv::command]` and `#[altv::stop]`
+    #[linkme::distributed_slice]
+    pub static REGISTRY: [fn()] = [..];
 
     pub fn init(
         name: ResourceName,
@@ -151,5 +201,9 @@ pub mod __internal {
         module_handlers: ModuleHandlers,
     ) {
         core_init(name, resource_state, module_handlers);
+
+        for register in REGISTRY {
+            register();
+        }
     }
 }
@@ -32,5 +32,24 @@
 //!
 //! [Documentation is here](trait.StreamSyncedCheckpointMeta.html).
 //!
+//! # Typed keys
+//! Every kind of meta can be accessed with [`MetaKey`](struct.MetaKey.html),
+//! so you don't need to match `MValue` manually every time you read it.
+//!
+//! [Documentation is here](struct.MetaKey.html).
+//!
+//! # Subscribing to changes of specific key
+//! Global meta: [`on_change`](fn.on_change.html), [`on_synced_change`](fn.on_synced_change.html).
+//!
+//! Meta of objects: `on_synced_meta_change`, `on_stream_synced_meta_change` and `on_local_meta_change` methods
+//! of meta traits, for example [`SyncedBaseObjectMeta`](trait.SyncedBaseObjectMeta.html#method.on_synced_meta_change).
+//!
+//! # Snapshots
+//! All keys and values of normal, synced, stream synced or local meta of object can be copied
+//! and later applied to the same or another object, for example, when vehicle is respawned.
+//! Snapshots can be converted to MValue, so they can also be saved as JSON and applied after server restart.
+//!
+//! [Documentation is here](struct.MetaSnapshot.html).
+//!
 
 pub use core_resource::exports::meta::*;
@@ -81,6 +81,82 @@
 //! const dict = vehicle.getMeta("example")
 //! alt.log("dict:", dict) // dict: { example: 123 }
 //! ```
+//!
+//! # Comparing, printing and forwarding
+//! `MValue` and `MValueList` can be cloned, compared (`NaN` is equal to `NaN`, base objects are
+//! equal if they are the same object) and printed the same way as `alt.log` in JS does.
+//! Received values can also be sent back unchanged, since `&MValue` can be converted to mvalue.
+//!
+//! ```rust
+//! altv::events::on_client("example", |c| {
+//!     altv::log!("received: {}", c.args); // received: [1, "text", { key: true }]
+//!     altv::events::emit_all_clients!("example", c.args.get(0)?)
+//! });
+//! ```
+//!
+//! # Functions
+//! Functions received from other resources (for example, as argument of local event)
+//! are deserialized as `MValue::Function` and can be called from Rust,
+//! see [`MValueFunction`](struct.MValueFunction.html).
+//!
+//! # Byte arrays
+//! Received `ByteArray` values are not copied, [`ByteArray`](struct.ByteArray.html) derefs to `&[u8]`
+//! which reads bytes directly from alt:V value.
+//! `&[u8]`, `Box<[u8]>`, `ByteArray` and `bytes::Bytes` (with `bytes` feature) are sent as `ByteArray`
+//! (alt:V copies the bytes once when value is created), but `Vec<u8>` is sent as list of numbers.
+//!
+//! **Breaking change:** `MValue::ByteArray` and `get_byte_array_at` used to contain `Vec<u8>`, now they contain `ByteArray`.
+//! It derefs to `&[u8]` and can be converted to `Vec<u8>` with `into_vec()` or `Vec::from`,
+//! [`get_byte_array_vec_at`](struct.MValueList.html#method.get_byte_array_vec_at) returns a copy as `Vec<u8>`.
+//!
+//! Large payloads can be compressed using [`compression`](compression/index.html) helpers
+//! (`zstd` and `lz4` features).
+//!
+//! ```rust
+//! altv::events::on_client("upload", |c| {
+//!     let bytes: &[u8] = c.args.get_byte_array_at(0)?;
+//!     altv::events::emit_all_clients!("upload", bytes)
+//! });
+//! ```
+//!
+//! # JSON
+//! `MValue` can be converted to JSON and back, types which don't exist in JSON
+//! (`Vector3`, `RGBA`, `ByteArray`, base objects, etc.) are encoded as objects with `"type"` key.
+//! See [`MValue::to_json`](enum.MValue.html#method.to_json) for the full description of encoding.
+//!
+//! ```rust
+//! let value = altv::mvalue::MValue::from_json(&altv::serde_json::json!([1, { "type": "Player", "id": 1 }]));
+//! altv::log!("{}", value.to_json()); // [1,{"id":1,"type":"Player"}]
+//!
+//! // serde_json::Value can also be used directly
+//! altv::meta::entry("config").set(altv::serde_json::json!({ "max_players": 100 }))?;
+//! ```
+//!
+//! # Derive
+//! Structs and enums can be converted to MValue and back using `#[derive(IntoMValue, FromMValue)]`,
+//! structs are converted to dict, field names can be renamed for JS using `#[mvalue(rename_all = "camelCase")]`.
+//! See [`IntoMValue`](derive.IntoMValue.html) for all supported attributes.
+//!
+//! ```rust
+//! use altv::mvalue::{FromMValue, IntoMValue};
+//!
+//! #[derive(IntoMValue, FromMValue)]
+//! #[mvalue(rename_all = "camelCase")]
+//! struct Stats {
+//!     kill_count: u32, // sent as "killCount"
+//!     #[mvalue(skip)]
+//!     cached_rank: Option<u32>,
+//! }
+//!
+//! altv::events::emit_client!("stats", player, Stats { kill_count: 1, cached_rank: None })?;
+//!
+//! altv::events::on_client("stats", |c| {
+//!     let stats = Stats::try_from(c.args.get(0)?)?;
+//!     Ok(())
+//! });
+//! ```
 pub use core_resource::exports::mvalue::{
-    mvalue_dict as dict, mvalue_list as list, MValue, MValueList,
+    compression, mvalue_dict as dict, mvalue_list as list, ByteArray, FromMValue, MValue,
+    MValueFunction, MValueList,
 };
+pub use mvalue_derive::{FromMValue, IntoMValue};
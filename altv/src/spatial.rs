@@ -0,0 +1,18 @@
+//! # Spatial queries
+//!
+//! Rust-side grid of entity positions (players, vehicles, peds and network objects),
+//! which answers range, box and nearest-N queries without calling alt:V for each of them,
+//! unlike [`get_entities_in_range`](../fn.get_entities_in_range.html) and [`get_closest_entities`](../fn.get_closest_entities.html).
+//!
+//! Grid is built on the first query and refreshed once per tick after that,
+//! so returned entities are at most one tick behind.
+//!
+//! Queries are done in exact dimension, entities in other dimensions (including [`GLOBAL_DIMENSION`](../constant.GLOBAL_DIMENSION.html)) are not returned.
+//!
+//! # Examples
+//! ```rust
+//! let nearby_players: Vec<altv::PlayerContainer> =
+//!     altv::spatial::in_range::<altv::Player>(vehicle.pos()?, 50.0, vehicle.dimension()?);
+//! ```
+
+pub use core_resource::exports::spatial::*;
@@ -14,6 +14,14 @@ macro_rules! on_base_object_event {
                     );
                     return;
                 }
+                if manager.is_stopping(&$resource_name) {
+                    logger::debug!(
+                        "{} resource is stopping: {}",
+                        stringified_method_name,
+                        $resource_name
+                    );
+                    return;
+                }
 
                 let base_object_type = unsafe { altv_sdk::helpers::get_base_object_type($base_object.as_ptr()) };
 
@@ -64,8 +64,20 @@ extern "C" fn resource_stop(resource_name: &str) {
     let resource_name = resource_name.to_string();
     logger::debug!("resource_stop: {resource_name}");
 
+    // taken out of the manager before stop hook is called,
+    // so it can use the module too (for example, start or stop other resources),
+    // base object events and sdk events of this resource are ignored until it's destroyed
+    let controller = RESOURCE_MANAGER_INSTANCE.with(|manager| {
+        let mut manager = manager.borrow_mut();
+        manager.add_stopping_status(resource_name.clone());
+        manager.take(&resource_name)
+    });
+    if let Some(controller) = controller {
+        controller.resource_for_module.on_stop();
+        controller.destroy();
+    }
     RESOURCE_MANAGER_INSTANCE.with(|manager| {
-        manager.borrow_mut().remove(&resource_name);
+        manager.borrow_mut().remove_stopping_status(&resource_name);
     });
     EVENT_MANAGER_INSTANCE.with(|manager| {
         manager.borrow_mut().resource_stopped(&resource_name);
@@ -121,6 +133,10 @@ extern "C" fn resource_on_event(resource_name: &str, event: altv_sdk::CEventPtr)
 
     RESOURCE_MANAGER_INSTANCE.with(|manager| {
         let manager = manager.borrow();
+        if manager.is_stopping(&resource_name) {
+            logger::debug!("resource_on_event resource is stopping: {resource_name}");
+            return;
+        }
         manager
             .get_resource_for_module_by_name(&resource_name)
             .unwrap_or_else(|| {
@@ -22,12 +22,20 @@ impl ResourceController {
             resource_for_module,
         }
     }
+
+    pub fn destroy(self) {
+        // workaround to fix crash due to drop_in_place of boxed closures
+        // core::ptr::drop_in_place<alloc::boxed::Box<dyn$<core::ops::function::Fn<...
+        drop(self.resource_for_module);
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct ResourceManager {
     resources: HashMap<ResourceName, ResourceController>,
     pending_start_resources: HashSet<ResourceName>,
+    /// Resources which were taken out of the manager and are running their stop logic
+    stopping_resources: HashSet<ResourceName>,
 }
 
 impl ResourceManager {
@@ -47,18 +55,28 @@ impl ResourceManager {
         self.pending_start_resources.contains(name)
     }
 
+    pub fn add_stopping_status(&mut self, name: ResourceName) {
+        self.stopping_resources.insert(name);
+    }
+
+    pub fn remove_stopping_status(&mut self, name: &str) {
+        self.stopping_resources.remove(name);
+    }
+
+    pub fn is_stopping(&self, name: &str) -> bool {
+        self.stopping_resources.contains(name)
+    }
+
     pub fn add(&mut self, name: ResourceName, resource: ResourceController) {
         self.resources.insert(name, resource);
     }
 
-    pub fn remove(&mut self, resource: &str) {
-        if let Some(controller) = self.resources.remove(resource) {
-            // workaround to fix crash due to drop_in_place of boxed closures
-            // core::ptr::drop_in_place<alloc::boxed::Box<dyn$<core::ops::function::Fn<...
-            drop(controller.resource_for_module);
-        } else {
-            logger::error!("ResourceManager remove unknown resource: {resource}");
+    pub fn take(&mut self, resource: &str) -> Option<ResourceController> {
+        let controller = self.resources.remove(resource);
+        if controller.is_none() {
+            logger::error!("ResourceManager take unknown resource: {resource}");
         }
+        controller
     }
 
     pub fn get_resource_for_module_by_name(&self, name: &str) -> Option<&ResourceForModule> {
@@ -94,6 +94,13 @@ MValueWrapper convert_mvalue_mut_wrapper_to_const(MValueMutWrapper mut_wrapper)
     return wrapper;
 }
 
+MValueMutWrapper convert_mvalue_wrapper_to_mut(MValueWrapper wrapper) {
+    MValueMutWrapper mut_wrapper;
+    // copy, so value is not shared between different owners (e.g. meta of two objects)
+    mut_wrapper.ptr = std::make_shared<alt::MValue>(wrapper.ptr->Get()->Clone());
+    return mut_wrapper;
+}
+
 using MValueDictPair = std::pair<std::string, MValueWrapper>;
 
 class MValueDictPairWrapper {
@@ -434,10 +441,11 @@ size_t get_mvalue_byte_array_size(MValueWrapper mvalue) {
     return byte_arr->GetSize();
 }
 
-void get_mvalue_byte_array(MValueWrapper mvalue, u8* data) {
+// returned pointer is valid as long as mvalue is alive
+const u8* get_mvalue_byte_array_data(MValueWrapper mvalue) {
     assert(mvalue.ptr->Get()->GetType() == alt::IMValue::Type::BYTE_ARRAY);
     auto byte_arr = mvalue.ptr->As<alt::IMValueByteArray>().Get();
-    std::memcpy(data, byte_arr->GetData(), byte_arr->GetSize());
+    return byte_arr->GetData();
 }
 
 RGBAWrapper get_mvalue_rgba(MValueWrapper mvalue) {
@@ -547,6 +555,19 @@ alt::MValueArgs mvalue_wrapper_vec_to_alt(MValueWrapperVec mvalue_vec) {
     return args;
 }
 
+MValueWrapper call_mvalue_function(MValueWrapper mvalue, MValueWrapperVec args) {
+    assert(mvalue.ptr->Get()->GetType() == alt::IMValue::Type::FUNCTION);
+    auto result = mvalue.ptr->As<alt::IMValueFunction>().Get()->Call(mvalue_wrapper_vec_to_alt(args));
+
+    MValueWrapper wrapper;
+    wrapper.ptr = std::make_shared<alt::MValueConst>(result);
+    return wrapper;
+}
+
+bool is_same_mvalue(MValueWrapper a, MValueWrapper b) {
+    return a.ptr->Get() == b.ptr->Get();
+}
+
 void trigger_local_event(std::string event_name, MValueWrapperVec mvalue_vec) {
     alt::ICore::Instance().TriggerLocalEvent(event_name, mvalue_wrapper_vec_to_alt(mvalue_vec));
 }
@@ -159,6 +159,7 @@ pub mod ffi {
         // mvalue
         generate!("MValueWrapper")
         generate!("convert_mvalue_mut_wrapper_to_const")
+        generate!("convert_mvalue_wrapper_to_mut")
         generate!("create_mvalue_vec")
         generate!("push_to_mvalue_vec")
         generate!("get_mvalue_type")
@@ -175,8 +176,10 @@ pub mod ffi {
         generate!("get_mvalue_vector3")
         generate!("get_mvalue_vector2")
         generate!("get_mvalue_byte_array_size")
-        generate!("get_mvalue_byte_array")
+        generate!("get_mvalue_byte_array_data")
         generate!("get_mvalue_rgba")
+        generate!("call_mvalue_function")
+        generate!("is_same_mvalue")
 
         generate!("create_mvalue_bool")
         generate!("create_mvalue_double")
@@ -33,4 +33,8 @@ impl ResourceForModule {
     ) {
         self.handlers.on_base_object_destroy.as_ref().unwrap()(base_object, base_object_type);
     }
+
+    pub fn on_stop(&self) {
+        self.handlers.on_stop.as_ref().unwrap()();
+    }
 }
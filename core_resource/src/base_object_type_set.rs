@@ -0,0 +1,138 @@
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr, BitOrAssign, Not},
+};
+
+use altv_sdk::BaseObjectType;
+
+/// Set of [`BaseObjectType`](enum.BaseObjectType.html)s used as filter in
+/// [`get_entities_in_range`](fn.get_entities_in_range.html), [`get_closest_entities`](fn.get_closest_entities.html)
+/// and [`get_entities_in_dimension`](fn.get_entities_in_dimension.html).
+///
+/// Each type is represented by bit `1 << type` as alt:V expects.
+///
+/// # Examples
+/// ```rust
+/// let types = altv::BaseObjectTypeSet::PLAYER | altv::BaseObjectType::Vehicle;
+/// let entities = altv::get_entities_in_range(pos, 50, altv::DEFAULT_DIMENSION, types);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BaseObjectTypeSet(u64);
+
+impl BaseObjectTypeSet {
+    pub const PLAYER: Self = Self::from_type(BaseObjectType::Player);
+    pub const VEHICLE: Self = Self::from_type(BaseObjectType::Vehicle);
+    pub const PED: Self = Self::from_type(BaseObjectType::Ped);
+    pub const NETWORK_OBJECT: Self = Self::from_type(BaseObjectType::NetworkObject);
+    /// Players, vehicles, peds and network objects
+    pub const ENTITIES: Self =
+        Self(Self::PLAYER.0 | Self::VEHICLE.0 | Self::PED.0 | Self::NETWORK_OBJECT.0);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn from_type(base_object_type: BaseObjectType) -> Self {
+        Self(1 << base_object_type as u8)
+    }
+
+    /// Creates set from raw bitmask
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Raw bitmask passed to alt:V
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn with(self, base_object_type: BaseObjectType) -> Self {
+        Self(self.0 | Self::from_type(base_object_type).0)
+    }
+
+    pub const fn contains(&self, base_object_type: BaseObjectType) -> bool {
+        self.0 & Self::from_type(base_object_type).0 != 0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn insert(&mut self, base_object_type: BaseObjectType) {
+        *self = self.with(base_object_type);
+    }
+
+    pub fn remove(&mut self, base_object_type: BaseObjectType) {
+        self.0 &= !Self::from_type(base_object_type).0;
+    }
+}
+
+impl Debug for BaseObjectTypeSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut set = f.debug_set();
+        for bit in 0..u64::BITS as u8 {
+            if self.0 & (1 << bit) == 0 {
+                continue;
+            }
+            match BaseObjectType::try_from(bit) {
+                Ok(base_object_type) => set.entry(&base_object_type),
+                Err(()) => set.entry(&bit),
+            };
+        }
+        set.finish()
+    }
+}
+
+impl From<BaseObjectType> for BaseObjectTypeSet {
+    fn from(value: BaseObjectType) -> Self {
+        Self::from_type(value)
+    }
+}
+
+impl From<u64> for BaseObjectTypeSet {
+    fn from(value: u64) -> Self {
+        Self::from_bits(value)
+    }
+}
+
+impl<const N: usize> From<[BaseObjectType; N]> for BaseObjectTypeSet {
+    fn from(value: [BaseObjectType; N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl FromIterator<BaseObjectType> for BaseObjectTypeSet {
+    fn from_iter<I: IntoIterator<Item = BaseObjectType>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::empty(), Self::with)
+    }
+}
+
+impl<T: Into<BaseObjectTypeSet>> BitOr<T> for BaseObjectTypeSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: T) -> Self::Output {
+        Self(self.0 | rhs.into().0)
+    }
+}
+
+impl<T: Into<BaseObjectTypeSet>> BitOrAssign<T> for BaseObjectTypeSet {
+    fn bitor_assign(&mut self, rhs: T) {
+        self.0 |= rhs.into().0;
+    }
+}
+
+impl<T: Into<BaseObjectTypeSet>> BitAnd<T> for BaseObjectTypeSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: T) -> Self::Output {
+        Self(self.0 & rhs.into().0)
+    }
+}
+
+impl Not for BaseObjectTypeSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
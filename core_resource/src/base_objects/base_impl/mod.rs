@@ -3,7 +3,9 @@ use std::rc::Rc;
 pub(crate) mod base_object;
 pub(crate) mod base_ptr;
 pub(crate) mod inherit_ptrs;
+pub(crate) mod user_data;
 pub(crate) mod valid;
+pub(crate) mod weak;
 pub(crate) mod wrapper;
 
 pub(crate) type BaseObjectContainer<T, InheritPtrs = ()> =
@@ -0,0 +1,72 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+use super::{valid::ValidBaseObject, wrapper::BaseObjectWrapper};
+use crate::SomeResult;
+
+/// Rust values attached to base object, one value per type
+#[derive(Default)]
+pub(crate) struct UserData(RefCell<HashMap<TypeId, Rc<dyn Any>>>);
+
+impl UserData {
+    /// Takes all values out, called when base object is removed or destroyed.
+    /// Values are dropped by the caller after `Resource.base_objects` borrow is released,
+    /// so their `Drop` can use base objects (including user data of other base objects).
+    pub(crate) fn take(&self) -> HashMap<TypeId, Rc<dyn Any>> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+}
+
+impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs> {
+    /// Returns value of type `D` attached to this base object using [`insert_data`](#method.insert_data).
+    ///
+    /// # Examples
+    /// ```rust
+    /// struct Session {
+    ///     logged_in: std::cell::Cell<bool>,
+    /// }
+    ///
+    /// altv::events::on_player_connect(|c| {
+    ///     c.player.insert_data(Session { logged_in: false.into() })?;
+    ///     Ok(())
+    /// });
+    ///
+    /// altv::events::on_client("login", |c| {
+    ///     if let Some(session) = c.player.data::<Session>() {
+    ///         session.logged_in.set(true);
+    ///     }
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn data<D: 'static>(&self) -> Option<Rc<D>> {
+        let value = self.user_data.0.borrow().get(&TypeId::of::<D>())?.clone();
+        Some(value.downcast().unwrap())
+    }
+
+    /// Attaches value to this base object, replacing the previous value of the same type (which is returned).
+    /// Values are dropped when base object is removed (for example, player disconnected) or destroyed.
+    ///
+    /// Returns error if base object is already removed or destroyed.
+    pub fn insert_data<D: 'static>(&self, data: D) -> SomeResult<Option<Rc<D>>> {
+        self.assert_valid()?;
+        let previous = self
+            .user_data
+            .0
+            .borrow_mut()
+            .insert(TypeId::of::<D>(), Rc::new(data));
+        Ok(previous.map(|value| value.downcast().unwrap()))
+    }
+
+    pub fn remove_data<D: 'static>(&self) -> Option<Rc<D>> {
+        let value = self.user_data.0.borrow_mut().remove(&TypeId::of::<D>())?;
+        Some(value.downcast().unwrap())
+    }
+
+    pub fn has_data<D: 'static>(&self) -> bool {
+        self.user_data.0.borrow().contains_key(&TypeId::of::<D>())
+    }
+}
@@ -0,0 +1,57 @@
+use std::{fmt::Debug, rc::Rc};
+
+use super::{valid::ValidBaseObject, wrapper::BaseObjectWrapper, BaseObjectContainer};
+
+/// Weak reference to base object, it does not keep base object wrapper alive
+/// and can be safely captured in long-lived closures (timers, event handlers, etc.).
+///
+/// # Examples
+/// ```rust
+/// let weak_player = player.downgrade();
+///
+/// altv::set_interval(
+///     move || {
+///         // `None` if player disconnected
+///         let Some(player) = weak_player.upgrade() else {
+///             return Ok(());
+///         };
+///         altv::log!("player {} is still online", player.name()?);
+///         Ok(())
+///     },
+///     1000,
+/// );
+/// ```
+pub struct Weak<T>(std::rc::Weak<T>);
+
+impl<T, InheritPtrs: Clone> Weak<BaseObjectWrapper<T, InheritPtrs>> {
+    /// Returns base object if it was not removed or destroyed
+    pub fn upgrade(&self) -> Option<BaseObjectContainer<T, InheritPtrs>> {
+        self.0.upgrade().filter(|base_object| base_object.valid())
+    }
+
+    /// Returns `true` if both weak references point to the same base object
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.0.ptr_eq(&other.0)
+    }
+}
+
+impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs> {
+    /// Creates [`Weak`](struct.Weak.html) reference to this base object
+    pub fn downgrade(self: &Rc<Self>) -> Weak<Self> {
+        Weak(Rc::downgrade(self))
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, InheritPtrs: Clone> Debug for Weak<BaseObjectWrapper<T, InheritPtrs>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Weak")
+            .field("alive", &self.upgrade().is_some())
+            .finish()
+    }
+}
@@ -1,13 +1,52 @@
-use std::{cell::RefCell, ptr::NonNull, rc::Rc};
+use std::{
+    any::{Any, TypeId},
+    cell::{Cell, RefCell},
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hash, Hasher},
+    ptr::NonNull,
+    rc::Rc,
+    time::SystemTime,
+};
 
-use super::{base_object::BaseObject, BaseObjectContainer};
+use super::{base_object::BaseObject, user_data::UserData, BaseObjectContainer};
 use crate::{
     meta::base_object::{normal_meta::NormalBaseObjectMeta, synced_meta::SyncedBaseObjectMeta},
     SomeResult, VoidResult,
 };
 
+thread_local! {
+    static NEXT_GENERATION: Cell<u64> = Cell::new(1);
+    static GENERATION_EPOCH: u64 = new_generation_epoch();
+}
+
+/// Generations start from 1 every time resource is loaded, so they are paired with epoch
+/// which is different in every load (handles stored in database should not resolve after restart)
+pub(crate) fn generation_epoch() -> u64 {
+    GENERATION_EPOCH.with(|epoch| *epoch)
+}
+
+fn new_generation_epoch() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    SystemTime::now().hash(&mut hasher);
+    // 53 bits, so it can be represented exactly as JS number
+    hasher.finish() & ((1 << 53) - 1)
+}
+
+/// User data and components of removed or destroyed base object.
+/// Must be dropped after `Resource.base_objects` borrow is released,
+/// because `Drop` of user values can use base objects (for example, destroy owned vehicle).
+#[must_use]
+pub(crate) struct DetachedValues {
+    _user_data: HashMap<TypeId, Rc<dyn Any>>,
+    _components: Vec<Box<dyn Any>>,
+}
+
 pub struct BaseObjectWrapper<T, InheritPtrs: Clone = ()> {
     pub(crate) value: RefCell<BaseObject<T, InheritPtrs>>,
+    persistent: Cell<bool>,
+    /// Unique in this resource, ids of base objects can be reused after destroy but generations can't
+    generation: u64,
+    pub(super) user_data: UserData,
 }
 
 impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs> {
@@ -22,9 +61,16 @@ impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs> {
                 base_ptr: Some(base_ptr),
                 inherit_ptrs: Some(inherit_ptrs),
             }),
+            persistent: Cell::new(false),
+            generation: NEXT_GENERATION.with(|next| next.replace(next.get() + 1)),
+            user_data: UserData::default(),
         })
     }
 
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
     pub(crate) fn ptr(&self) -> SomeResult<NonNull<T>> {
         self.value.try_borrow()?.ptr()
     }
@@ -34,7 +80,45 @@ impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs> {
     }
 
     pub(crate) fn internal_destroy(&self) -> VoidResult {
-        self.value.try_borrow_mut()?.internal_destroy()
+        let detached = self.internal_destroy_detached()?;
+        drop(detached);
+        Ok(())
+    }
+
+    /// Same as [`internal_destroy`](#method.internal_destroy), but values attached to base object
+    /// are returned instead of dropped, used when `Resource.base_objects` is borrowed
+    pub(crate) fn internal_destroy_detached(&self) -> SomeResult<DetachedValues> {
+        self.value.try_borrow_mut()?.internal_destroy()?;
+        Ok(self.detach_values())
+    }
+
+    /// Called when base object is removed by alt:V (for example, player disconnected)
+    pub(crate) fn remove_externally(&self) -> SomeResult<DetachedValues> {
+        self.value.try_borrow_mut()?.clear_pointers();
+        Ok(self.detach_values())
+    }
+
+    fn detach_values(&self) -> DetachedValues {
+        DetachedValues {
+            _user_data: self.user_data.take(),
+            _components: crate::components::remove_all(self.generation),
+        }
+    }
+
+    /// Base objects created by this resource are destroyed when it stops,
+    /// persistent ones are kept on the server.
+    ///
+    /// # Examples
+    /// ```rust
+    /// let vehicle = altv::Vehicle::new("sultan2", 0, 0)?;
+    /// vehicle.set_persistent(true);
+    /// ```
+    pub fn set_persistent(&self, persistent: bool) {
+        self.persistent.set(persistent);
+    }
+
+    pub fn persistent(&self) -> bool {
+        self.persistent.get()
     }
 }
 
@@ -205,6 +205,10 @@ impl EntityPool {
     pub fn get_by_id(&self, id: u32) -> Option<&AnyEntity> {
         self.base_objects.get(&id)
     }
+
+    pub(crate) fn all(&self) -> impl Iterator<Item = &AnyEntity> {
+        self.base_objects.values()
+    }
 }
 
 #[macro_export]
@@ -24,7 +24,7 @@ pub(self) mod wrappers {
     macro_rules! extra_pool_enum {
         (@internal $any_name: ident, $name: ident, $raw_ptr_type: ty: [ $( $variant: ident, $container: ty; )+ ]) => {
             paste::paste! {
-                #[derive(Debug)]
+                #[derive(Debug, Clone)]
                 pub enum $any_name { $(
                     $variant($container),
                 )+ }
@@ -36,6 +36,20 @@ pub(self) mod wrappers {
                             $any_name::$variant(e) => Ok($crate::helpers::base_ptr_to_raw!(e.raw_base_ptr()?, [<$name:snake>])),
                         )+}
                     }
+
+                    pub fn base_object_type(&self) -> altv_sdk::BaseObjectType {
+                        match self { $(
+                            $any_name::$variant(_) => altv_sdk::BaseObjectType::$variant,
+                        )+}
+                    }
+                }
+
+                impl From<$any_name> for AnyBaseObject {
+                    fn from(value: $any_name) -> Self {
+                        match value { $(
+                            $any_name::$variant(e) => AnyBaseObject::$variant(e),
+                        )+}
+                    }
                 }
 
                 impl TryFrom<AnyBaseObject> for $any_name {
@@ -45,7 +59,7 @@ pub(self) mod wrappers {
                         $(
                             AnyBaseObject::$variant(e) => $any_name::$variant(e),
                         )+
-                            base_object => anyhow::bail!("cannot convert: {base_object:?} to AnyEntity"),
+                            base_object => anyhow::bail!("cannot convert: {base_object:?} to {}", stringify!($any_name)),
                         })
                     }
                 }
@@ -96,6 +110,24 @@ pub(self) mod wrappers {
         Ped, PedContainer;
         NetworkObject, NetworkObjectContainer;
     ]);
+
+    impl From<AnyEntity> for AnyWorldObject {
+        fn from(value: AnyEntity) -> Self {
+            match value {
+                AnyEntity::Player(e) => AnyWorldObject::Player(e),
+                AnyEntity::Vehicle(e) => AnyWorldObject::Vehicle(e),
+                AnyEntity::Ped(e) => AnyWorldObject::Ped(e),
+                AnyEntity::NetworkObject(e) => AnyWorldObject::NetworkObject(e),
+            }
+        }
+    }
+
+    impl TryFrom<AnyWorldObject> for AnyEntity {
+        type Error = anyhow::Error;
+        fn try_from(value: AnyWorldObject) -> Result<Self, Self::Error> {
+            AnyBaseObject::from(value).try_into()
+        }
+    }
 }
 
 pub use wrappers::*;
@@ -1,39 +1,76 @@
-use crate::VoidResult;
-use std::{collections::HashMap, fmt::Debug, ptr::NonNull};
+use crate::{SomeResult, VoidResult};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    ptr::NonNull,
+};
 
-use super::base_impl::BaseObjectContainer;
+use super::{base_impl::BaseObjectContainer, DetachedValues};
 
 pub(crate) struct BaseObjectManager<T, InheritPtrs: Clone = ()> {
     objects: HashMap<NonNull<T>, BaseObjectContainer<T, InheritPtrs>>,
+    /// base objects created by this resource
+    created: HashSet<NonNull<T>>,
+    /// used for resolving handles
+    generations: HashMap<u64, NonNull<T>>,
 }
 
 impl<T, InheritPtrs: Clone> BaseObjectManager<T, InheritPtrs> {
     pub fn add(&mut self, ptr: NonNull<T>, base_object: BaseObjectContainer<T, InheritPtrs>) {
+        self.generations.insert(base_object.generation(), ptr);
         self.objects.insert(ptr, base_object);
     }
 
+    pub fn add_created(
+        &mut self,
+        ptr: NonNull<T>,
+        base_object: BaseObjectContainer<T, InheritPtrs>,
+    ) {
+        self.created.insert(ptr);
+        self.add(ptr, base_object);
+    }
+
     pub fn remove(&mut self, ptr: NonNull<T>) -> VoidResult {
         logger::debug!("remove ptr: {ptr:?}");
-        if self.objects.remove(&ptr).is_some() {
+        self.created.remove(&ptr);
+        if let Some(obj) = self.objects.remove(&ptr) {
+            self.generations.remove(&obj.generation());
             Ok(())
         } else {
             anyhow::bail!("unknown base object")
         }
     }
 
-    pub fn remove_externally(&mut self, ptr: NonNull<T>) -> VoidResult {
+    pub fn remove_externally(&mut self, ptr: NonNull<T>) -> SomeResult<DetachedValues> {
+        self.created.remove(&ptr);
         if let Some(obj) = self.objects.remove(&ptr) {
-            obj.value.try_borrow_mut()?.clear_pointers();
-            Ok(())
+            self.generations.remove(&obj.generation());
+            obj.remove_externally()
         } else {
             anyhow::bail!("unknown base object")
         }
     }
 
+    pub fn remove_all_externally(&mut self, detached: &mut Vec<DetachedValues>) -> VoidResult {
+        self.created.clear();
+        self.generations.clear();
+        for (_, obj) in self.objects.drain() {
+            detached.push(obj.remove_externally()?);
+        }
+        Ok(())
+    }
+
     pub fn get_by_ptr(&self, ptr: NonNull<T>) -> Option<BaseObjectContainer<T, InheritPtrs>> {
         self.objects.get(&ptr).cloned()
     }
 
+    pub fn get_by_generation(
+        &self,
+        generation: u64,
+    ) -> Option<BaseObjectContainer<T, InheritPtrs>> {
+        self.get_by_ptr(*self.generations.get(&generation)?)
+    }
+
     pub fn has(&self, ptr: NonNull<T>) -> bool {
         self.objects.contains_key(&ptr)
     }
@@ -41,12 +78,24 @@ impl<T, InheritPtrs: Clone> BaseObjectManager<T, InheritPtrs> {
     pub fn all(&self) -> Vec<BaseObjectContainer<T, InheritPtrs>> {
         self.objects.values().cloned().collect()
     }
+
+    /// Base objects created by this resource which are not marked as persistent
+    pub fn created_non_persistent(&self) -> Vec<BaseObjectContainer<T, InheritPtrs>> {
+        self.created
+            .iter()
+            .filter_map(|ptr| self.objects.get(ptr))
+            .filter(|obj| !obj.persistent())
+            .cloned()
+            .collect()
+    }
 }
 
 impl<T, InheritPtrs: Clone> Default for BaseObjectManager<T, InheritPtrs> {
     fn default() -> Self {
         Self {
             objects: Default::default(),
+            created: Default::default(),
+            generations: Default::default(),
         }
     }
 }
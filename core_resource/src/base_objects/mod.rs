@@ -7,13 +7,15 @@ mod objects;
 pub(crate) use base_impl::base_ptr::BasePtr;
 pub use base_impl::inherit_ptrs::BaseObjectInheritPtrs;
 pub use base_impl::valid::ValidBaseObject;
+pub use base_impl::weak::Weak;
+pub(crate) use base_impl::wrapper::{generation_epoch, DetachedValues};
 pub use base_impl::wrapper::BaseObjectWrapper;
 pub(crate) use base_impl::BaseObjectContainer;
 
 pub(crate) use manager::BaseObjectManager;
 pub(crate) use objects::Store;
 
-pub(crate) use objects::AnyBaseObject;
+pub use objects::AnyBaseObject;
 pub(crate) use objects::PendingDestroyOrCreation;
 
 pub(crate) use objects::blip;
@@ -3,9 +3,9 @@ use std::{cell::RefMut, fmt::Debug};
 
 use super::{
     extra_pools::{Entity, ExtraPools},
-    BaseObjectContainer, BaseObjectManager, BaseObjectWrapper,
+    BaseObjectContainer, BaseObjectManager, BaseObjectWrapper, BasePtr, DetachedValues,
 };
-use crate::{col_shape::ColShapy, sdk, world_object::WorldObject};
+use crate::{col_shape::ColShapy, sdk, world_object::WorldObject, SomeResult};
 
 macro_rules! base_objects {
     (@internal $(
@@ -44,6 +44,8 @@ macro_rules! base_objects {
                 #[allow(dead_code)]
                 pub type $name_ptr = NonNull<$name_struct>;
 
+                pub type [<Weak $manager_name>] = $crate::base_objects::Weak<$manager_name>;
+
                 impl Debug for $manager_name {
                     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                         write!(f, "{} {{ ... }}", stringify!($manager_name))
@@ -90,7 +92,7 @@ macro_rules! base_objects {
 
                             let container = Self::_new($ptr, base_ptr, inherit_ptrs);
 
-                            v.[<$manager_name_snake>].add($ptr, container.clone());
+                            v.[<$manager_name_snake>].add_created($ptr, container.clone());
 
                             $(
                                 $crate::resource::Resource::with_extra_base_object_pools_mut(|mut v, _| {
@@ -112,11 +114,73 @@ macro_rules! base_objects {
             }
         )+
 
-            #[derive(Debug)]
+            /// Base object of any type, returned when type is not known at compile time
+            /// (for example, from [`get_entities_in_range`](fn.get_entities_in_range.html))
+            ///
+            /// # Examples
+            /// ```rust
+            /// for base_object in altv::get_entities_in_range(pos, 50, altv::DEFAULT_DIMENSION, altv::BaseObjectTypeSet::ENTITIES) {
+            ///     if let Some(player) = base_object.as_player() {
+            ///         altv::log!("player: {}", player.name()?);
+            ///     }
+            ///     let vehicle: altv::SomeResult<altv::VehicleContainer> = base_object.try_into();
+            /// }
+            /// ```
+            #[derive(Debug, Clone)]
             pub enum AnyBaseObject { $(
                 $manager_name($manager_name_snake::$name_container),
             )+ }
 
+            impl AnyBaseObject {
+                pub fn base_object_type(&self) -> altv_sdk::BaseObjectType {
+                    match self { $(
+                        Self::$manager_name(_) => $base_type,
+                    )+ }
+                }
+
+            $(
+                pub fn [<as_ $manager_name_snake>](&self) -> Option<&$manager_name_snake::$name_container> {
+                    match self {
+                        Self::$manager_name(base_object) => Some(base_object),
+                        _ => None,
+                    }
+                }
+            )+
+            }
+
+        $(
+            impl From<$manager_name_snake::$name_container> for AnyBaseObject {
+                fn from(value: $manager_name_snake::$name_container) -> Self {
+                    Self::$manager_name(value)
+                }
+            }
+
+            impl TryFrom<AnyBaseObject> for $manager_name_snake::$name_container {
+                type Error = anyhow::Error;
+                fn try_from(value: AnyBaseObject) -> SomeResult<Self> {
+                    match value {
+                        AnyBaseObject::$manager_name(base_object) => Ok(base_object),
+                        base_object => anyhow::bail!(
+                            "cannot convert: {base_object:?} to {}",
+                            stringify!($name_container)
+                        ),
+                    }
+                }
+            }
+        )+
+
+            impl BasePtr for AnyBaseObject {
+                fn base_ptr(&self) -> SomeResult<altv_sdk::BaseObjectMutPtr> {
+                    match self { $(
+                        Self::$manager_name(base_object) => base_object.base_ptr(),
+                    )+ }
+                }
+
+                fn raw_base_ptr(&self) -> SomeResult<altv_sdk::BaseObjectRawMutPtr> {
+                    Ok(self.base_ptr()?.as_ptr())
+                }
+            }
+
             #[derive(Default)]
             pub struct Store {
             $(
@@ -173,29 +237,82 @@ macro_rules! base_objects {
                     }
                 }
 
-                pub fn on_remove(
+                /// Returned values must be dropped after `base_objects` borrow is released
+                pub(crate) fn on_remove(
                     &mut self,
                     base_ptr: NonNull<sdk::alt::IBaseObject>,
                     base_object_type: altv_sdk::BaseObjectType,
                     mut extra_pools: RefMut<ExtraPools>,
-                ) {
+                ) -> Option<DetachedValues> {
                     match base_object_type {
                     $(
                         $base_type => {
                             let ptr = $crate::helpers::base_ptr_to!(base_ptr.as_ptr(), $manager_name_snake);
                             // TEST unwrap
-                            self.[<$manager_name_snake>].remove_externally(ptr).unwrap();
+                            let detached = self.[<$manager_name_snake>].remove_externally(ptr).unwrap();
                         $(
                             extra_pools.[<$extra_pool:snake>].remove(base_ptr);
                         )?
+                            Some(detached)
                         }
                     )+
                         _ => {
-                            logger::error!("unknown base object type: {base_object_type:?}")
+                            logger::error!("unknown base object type: {base_object_type:?}");
+                            None
                         }
                     }
                 }
 
+                /// Called when resource is stopping,
+                /// destroys base objects created by this resource unless they are marked as persistent.
+                /// Returned values must be dropped after `base_objects` borrow is released
+                pub(crate) fn destroy_created(
+                    &mut self,
+                    mut extra_pools: RefMut<ExtraPools>,
+                ) -> Vec<DetachedValues> {
+                    let mut detached = vec![];
+                $(
+                    for base_object in self.[<$manager_name_snake>].created_non_persistent() {
+                        let result = (|| -> $crate::VoidResult {
+                            let ptr = base_object.ptr()?;
+                            $(
+                                extra_pools.[<$extra_pool:snake>].remove(base_object.base_ptr()?);
+                            )?
+                            self.[<$manager_name_snake>].remove(ptr)?;
+                            detached.push(base_object.internal_destroy_detached()?);
+                            Ok(())
+                        })();
+
+                        if let Err(error) = result {
+                            logger::error!(
+                                "failed to destroy {} created by this resource: {error:?}",
+                                stringify!($manager_name_snake)
+                            );
+                        }
+                    }
+                )+
+                    detached
+                }
+
+                /// Called when resource is stopping, makes all base objects invalid.
+                /// Returned values must be dropped after `base_objects` borrow is released
+                pub(crate) fn remove_all_externally(
+                    &mut self,
+                    mut extra_pools: RefMut<ExtraPools>,
+                ) -> Vec<DetachedValues> {
+                    let mut detached = vec![];
+                $(
+                    if let Err(error) = self.[<$manager_name_snake>].remove_all_externally(&mut detached) {
+                        logger::error!(
+                            "failed to remove {} base objects: {error:?}",
+                            stringify!($manager_name_snake)
+                        );
+                    }
+                )+
+                    *extra_pools = Default::default();
+                    detached
+                }
+
                 pub fn get_by_ptr(
                     &self,
                     base_ptr: NonNull<sdk::alt::IBaseObject>,
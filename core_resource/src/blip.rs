@@ -1,7 +1,7 @@
 use crate::{
     base_objects::{blip, extra_pools::AnyEntity, player},
     helpers,
-    rgba::RGBA,
+    rgba::{IntoRGBA, RGBA},
     sdk,
     vector::{Vector2, Vector3},
     SomeResult, VoidResult,
@@ -270,8 +270,8 @@ impl blip::Blip {
         Ok(())
     }
 
-    pub fn set_route_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color = color.into();
+    pub fn set_route_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
 
         unsafe {
             sdk::IBlip::SetRouteColor(self.raw_ptr()?, color.r(), color.g(), color.b(), color.a())
@@ -279,8 +279,8 @@ impl blip::Blip {
         Ok(())
     }
 
-    pub fn set_secondary_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color = color.into();
+    pub fn set_secondary_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
 
         unsafe {
             sdk::IBlip::SetSecondaryColor(
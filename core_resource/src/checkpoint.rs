@@ -1,3 +1,4 @@
+use crate::rgba::IntoRGBA;
 use crate::{
     base_objects::checkpoint, helpers,
     meta::checkpoint_stream_synced_meta::StreamSyncedCheckpointMeta, rgba::RGBA, sdk,
@@ -14,11 +15,11 @@ impl checkpoint::Checkpoint {
         pos: impl Into<Vector3>,
         radius: f32,
         height: f32,
-        color: impl Into<RGBA>,
+        color: impl IntoRGBA,
         streaming_distance: u32,
-    ) -> checkpoint::CheckpointContainer {
+    ) -> SomeResult<checkpoint::CheckpointContainer> {
         let pos = pos.into();
-        let color = color.into();
+        let color = color.into_rgba()?;
 
         let ptr = unsafe {
             sdk::ICore::CreateCheckpoint(
@@ -36,7 +37,7 @@ impl checkpoint::Checkpoint {
             )
         };
 
-        checkpoint::add_to_pool!(NonNull::new(ptr).unwrap())
+        Ok(checkpoint::add_to_pool!(NonNull::new(ptr).unwrap()))
     }
 
     pub fn checkpoint_type(&self) -> SomeResult<u8> {
@@ -78,8 +79,8 @@ impl checkpoint::Checkpoint {
         Ok(())
     }
 
-    pub fn set_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color = color.into();
+    pub fn set_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
 
         unsafe {
             sdk::ICheckpoint::SetColor(self.raw_ptr()?, color.r(), color.g(), color.b(), color.a())
@@ -106,6 +106,10 @@ pub trait ColShapy<InheritPtrs: inherit_ptrs::traits::ColShape>:
         Ok(self.inherit_ptrs()?.col_shape())
     }
 
+    fn id(&self) -> SomeResult<u32> {
+        Ok(unsafe { sdk::IColShape::GetID(self.raw_ptr()?) })
+    }
+
     fn players_only(&self) -> SomeResult<bool> {
         Ok(unsafe { sdk::IColShape::IsPlayersOnly(self.raw_ptr()?) })
     }
@@ -0,0 +1,463 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Debug,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    base_objects::{extra_pools::Entity, player},
+    client_events,
+    helpers::IntoString,
+    mvalue::Serializable,
+    resource::Resource,
+    script_events::{self, ClientEventContext},
+    IntoVoidResult, SomeResult, VoidResult,
+};
+
+/// Client event which is used by the alt:V chat resource to send and receive messages
+pub const CHAT_MESSAGE_EVENT: &str = "chat:message";
+pub const COMMAND_PREFIX: char = '/';
+
+pub type CommandHandler = Box<dyn FnMut(&CommandContext) -> VoidResult>;
+
+/// Checks if player is allowed to execute a command which requires some permission.
+///
+/// # Examples
+/// ```rust
+/// struct Admins;
+///
+/// impl altv::commands::CommandPermissions for Admins {
+///     fn has_permission(&self, player: &altv::PlayerContainer, permission: &str) -> altv::SomeResult<bool> {
+///         Ok(permission == "admin" && player.name()?.starts_with("[admin]"))
+///     }
+/// }
+///
+/// altv::commands::set_permissions(Admins);
+/// ```
+pub trait CommandPermissions {
+    fn has_permission(
+        &self,
+        player: &player::PlayerContainer,
+        permission: &str,
+    ) -> SomeResult<bool>;
+}
+
+impl<F> CommandPermissions for F
+where
+    F: Fn(&player::PlayerContainer, &str) -> SomeResult<bool>,
+{
+    fn has_permission(
+        &self,
+        player: &player::PlayerContainer,
+        permission: &str,
+    ) -> SomeResult<bool> {
+        self(player, permission)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CommandOptions {
+    /// Other names of the command, for example `["tp", "teleport"]`
+    pub aliases: Vec<String>,
+    /// Permission that is passed to [`CommandPermissions`](trait.CommandPermissions.html)
+    pub permission: Option<String>,
+    /// How often one player can execute the command
+    pub cooldown: Option<Duration>,
+}
+
+/// Converts raw command argument to Rust type
+pub trait CommandArg: Sized {
+    fn parse_arg(raw: &str) -> SomeResult<Self>;
+}
+
+impl CommandArg for String {
+    fn parse_arg(raw: &str) -> SomeResult<Self> {
+        Ok(raw.to_string())
+    }
+}
+
+impl CommandArg for bool {
+    fn parse_arg(raw: &str) -> SomeResult<Self> {
+        Ok(match raw.to_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => true,
+            "false" | "0" | "no" | "off" => false,
+            _ => anyhow::bail!("expected boolean, received: {raw:?}"),
+        })
+    }
+}
+
+macro_rules! impl_command_arg_from_str {
+    ($( $type: ty, )+) => {
+        $(
+            impl CommandArg for $type {
+                fn parse_arg(raw: &str) -> SomeResult<Self> {
+                    raw.parse::<$type>().map_err(|e| {
+                        anyhow::anyhow!("expected {}, received: {raw:?} ({e})", stringify!($type))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_command_arg_from_str!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64,);
+
+/// Player can be resolved by id or by part of the name (case insensitive)
+impl CommandArg for player::PlayerContainer {
+    fn parse_arg(raw: &str) -> SomeResult<Self> {
+        if let Ok(id) = raw.parse::<u32>() {
+            return player::Player::get_by_id(id);
+        }
+
+        let raw = raw.to_lowercase();
+        let mut found = vec![];
+
+        for player in player::Player::all() {
+            let name = player.name()?.to_lowercase();
+            if name == raw {
+                return Ok(player);
+            }
+            if name.contains(&raw) {
+                found.push(player);
+            }
+        }
+
+        match found.len() {
+            0 => anyhow::bail!("No player with name: {raw:?}"),
+            1 => Ok(found.remove(0)),
+            count => anyhow::bail!("Found {count} players with name: {raw:?}, be more specific"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CommandContext<'a> {
+    pub player: player::PlayerContainer,
+    /// Name of the command or alias which was used by player
+    pub name: &'a str,
+    pub args: &'a [String],
+}
+
+impl<'a> CommandContext<'a> {
+    /// # Examples
+    /// ```rust
+    /// altv::commands::add("heal", Default::default(), |ctx| {
+    ///     let target: altv::PlayerContainer = ctx.arg(0)?;
+    ///     let health: u16 = ctx.arg(1)?;
+    ///     target.set_health(health)?;
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn arg<T: CommandArg>(&self, index: usize) -> SomeResult<T> {
+        let Some(raw) = self.args.get(index) else {
+            anyhow::bail!(
+                "Command /{} argument at index: {index} is missing",
+                self.name
+            );
+        };
+        T::parse_arg(raw).map_err(|e| {
+            anyhow::anyhow!(
+                "Command /{} argument at index: {index} is invalid: {e}",
+                self.name
+            )
+        })
+    }
+
+    pub fn optional_arg<T: CommandArg>(&self, index: usize) -> SomeResult<Option<T>> {
+        if index >= self.args.len() {
+            return Ok(None);
+        }
+        self.arg(index).map(Some)
+    }
+
+    /// Joins all arguments starting from `from` index, for example, for `/me waves hand` `ctx.rest(0)` is `"waves hand"`
+    pub fn rest(&self, from: usize) -> String {
+        self.args.get(from..).unwrap_or_default().join(" ")
+    }
+
+    /// Sends message to player who executed this command using alt:V chat resource
+    pub fn reply(&self, message: impl IntoString) -> VoidResult {
+        reply(self.player.clone(), message)
+    }
+}
+
+fn reply(player: player::PlayerContainer, message: impl IntoString) -> VoidResult {
+    client_events::emit_client(
+        CHAT_MESSAGE_EVENT,
+        player,
+        vec![
+            Serializable::try_from(())?,
+            Serializable::try_from(message.into_string())?,
+        ],
+    )
+}
+
+struct Command {
+    options: CommandOptions,
+    // handlers and permissions are called outside of `with_commands_mut`,
+    // so they can add commands or set permissions themselves
+    handler: Rc<RefCell<CommandHandler>>,
+    last_calls: HashMap<u32, Instant>,
+}
+
+#[derive(Default)]
+pub struct CommandManager {
+    commands: Vec<Command>,
+    // command name or alias -> index in `commands`
+    names: HashMap<String, usize>,
+    permissions: Option<Rc<dyn CommandPermissions>>,
+    initialized: bool,
+}
+
+impl CommandManager {
+    fn init(&mut self) {
+        if self.initialized {
+            return;
+        }
+        self.initialized = true;
+
+        script_events::add_client_handler(CHAT_MESSAGE_EVENT, |c: &ClientEventContext| {
+            let Ok(message) = c.args.get_string_at(0) else {
+                return Ok(());
+            };
+            let Some(parsed) = parse_command(message) else {
+                return Ok(());
+            };
+            execute(c.player.clone(), parsed)
+        });
+    }
+
+    pub fn add(&mut self, name: String, options: CommandOptions, handler: CommandHandler) {
+        self.init();
+
+        let index = self.commands.len();
+        for name in std::iter::once(&name).chain(options.aliases.iter()) {
+            let name = name.to_lowercase();
+            if self.names.insert(name.clone(), index).is_some() {
+                logger::warn!("command /{name} was registered twice, overriding previous one");
+            }
+        }
+
+        self.commands.push(Command {
+            options,
+            handler: Rc::new(RefCell::new(handler)),
+            last_calls: HashMap::new(),
+        });
+    }
+}
+
+impl Debug for CommandManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CommandManager {{ commands: {} }}", self.commands.len())
+    }
+}
+
+/// Unknown commands are ignored, because they may be handled by another resource
+fn execute(player: player::PlayerContainer, (name, args): (String, Vec<String>)) -> VoidResult {
+    let Some((index, permission, permissions)) = Resource::with_commands_mut(|commands, _| {
+        let index = *commands.names.get(&name.to_lowercase())?;
+        Some((
+            index,
+            commands.commands[index].options.permission.clone(),
+            commands.permissions.clone(),
+        ))
+    }) else {
+        return Ok(());
+    };
+
+    if let Some(permission) = permission {
+        let allowed = match permissions {
+            Some(permissions) => permissions.has_permission(&player, &permission)?,
+            None => {
+                logger::warn!(
+                    "command /{name} requires permission: {permission:?}, but permissions are not set"
+                );
+                false
+            }
+        };
+        if !allowed {
+            return reply(player, format!("You are not allowed to use /{name}"));
+        }
+    }
+
+    let id = player.id()?;
+    let handler = Resource::with_commands_mut(|mut commands, _| {
+        let command = &mut commands.commands[index];
+        if let Some(cooldown) = command.options.cooldown {
+            if let Some(last_call) = command.last_calls.get(&id) {
+                let elapsed = last_call.elapsed();
+                if elapsed < cooldown {
+                    return Err(cooldown - elapsed);
+                }
+            }
+            // also removes cooldowns of disconnected players
+            command
+                .last_calls
+                .retain(|_, last_call| last_call.elapsed() < cooldown);
+            command.last_calls.insert(id, Instant::now());
+        }
+        Ok(command.handler.clone())
+    });
+    let handler = match handler {
+        Ok(handler) => handler,
+        Err(left) => {
+            let left = left.as_secs_f32().ceil();
+            return reply(player, format!("Wait {left}s before using /{name} again"));
+        }
+    };
+
+    let context = CommandContext {
+        player,
+        name: &name,
+        args: &args,
+    };
+    let Ok(mut handler) = handler.try_borrow_mut() else {
+        anyhow::bail!("command /{name} was executed recursively");
+    };
+    if let Err(error) = handler(&context) {
+        logger::error!("handler of command: /{name} failed with error: {error:?}");
+    }
+
+    Ok(())
+}
+
+/// Splits chat message to command name and arguments,
+/// arguments can be wrapped in double quotes to include spaces, e.g. `/kick 1 "bad words"`.
+/// Quote only opens an argument at its start, quotes inside of a word are kept as they are
+fn parse_command(message: &str) -> Option<(String, Vec<String>)> {
+    let message = message.trim().strip_prefix(COMMAND_PREFIX)?;
+
+    let mut parts = vec![];
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for char in message.chars() {
+        match char {
+            '"' if quoted => {
+                parts.push(std::mem::take(&mut current));
+                quoted = false;
+            }
+            '"' if current.is_empty() => quoted = true,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    parts.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+    let name = parts.remove(0);
+    Some((name, parts))
+}
+
+/// Registers new chat command, chat messages of players are handled only after
+/// the first command is added.
+///
+/// # Examples
+/// ```rust
+/// altv::commands::add("veh", Default::default(), |ctx| {
+///     let model: String = ctx.arg(0)?;
+///     altv::Vehicle::new(model.as_str(), ctx.player.pos()?, 0)?;
+///     Ok(())
+/// });
+///
+/// altv::commands::add(
+///     "tp",
+///     altv::commands::CommandOptions {
+///         aliases: vec!["teleport".to_string()],
+///         permission: Some("admin".to_string()),
+///         cooldown: Some(std::time::Duration::from_secs(5)),
+///     },
+///     |ctx| {
+///         let target: altv::PlayerContainer = ctx.arg(0)?;
+///         ctx.player.set_pos(target.pos()?)?;
+///         ctx.reply(format!("Teleported to {}", target.name()?))
+///     },
+/// );
+/// ```
+pub fn add<V: IntoVoidResult>(
+    name: impl IntoString,
+    options: CommandOptions,
+    mut handler: impl FnMut(&CommandContext) -> V + 'static,
+) {
+    Resource::with_commands_mut(|mut commands, _| {
+        commands.add(
+            name.into_string(),
+            options,
+            Box::new(move |c| handler(c).into_void_result()),
+        )
+    });
+}
+
+/// Sets permission checker for commands with `permission` option
+pub fn set_permissions(permissions: impl CommandPermissions + 'static) {
+    Resource::with_commands_mut(|mut commands, _| {
+        commands.permissions.replace(Rc::new(permissions));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_command;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_by_whitespace() {
+        assert_eq!(
+            parse_command("  /kick  1   reason "),
+            Some(("kick".to_string(), args(&["1", "reason"])))
+        );
+        assert_eq!(parse_command("/help"), Some(("help".to_string(), vec![])));
+    }
+
+    #[test]
+    fn ignores_messages_without_command() {
+        assert_eq!(parse_command("hello"), None);
+        assert_eq!(parse_command("/"), None);
+        assert_eq!(parse_command("/   "), None);
+    }
+
+    #[test]
+    fn quoted_args_include_spaces() {
+        assert_eq!(
+            parse_command(r#"/kick 1 "bad words" now"#),
+            Some(("kick".to_string(), args(&["1", "bad words", "now"])))
+        );
+        assert_eq!(
+            parse_command(r#"/say "" x"#),
+            Some(("say".to_string(), args(&["", "x"])))
+        );
+    }
+
+    #[test]
+    fn unclosed_quote_takes_rest_of_message() {
+        assert_eq!(
+            parse_command(r#"/say "hello world"#),
+            Some(("say".to_string(), args(&["hello world"])))
+        );
+    }
+
+    #[test]
+    fn quotes_inside_of_word_are_kept() {
+        assert_eq!(
+            parse_command(r#"/say a"b c""#),
+            Some(("say".to_string(), args(&[r#"a"b"#, r#"c""#])))
+        );
+        assert_eq!(
+            parse_command(r#"/say don"t "quote me""#),
+            Some(("say".to_string(), args(&[r#"don"t"#, "quote me"])))
+        );
+    }
+}
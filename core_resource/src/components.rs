@@ -0,0 +1,296 @@
+use std::{
+    any::{Any, TypeId},
+    cell::{Ref, RefCell, RefMut},
+    collections::HashMap,
+    fmt::Debug,
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use crate::{base_objects::ValidBaseObject, handle::HandleType, resource::Resource, SomeResult};
+
+/// Components of base objects, keyed by generation of base object (see [`Handle`](../struct.Handle.html))
+#[derive(Default)]
+pub struct ComponentStore {
+    /// base objects which have at least one component
+    base_objects: HashMap<u64, Box<dyn Any>>,
+    storages: HashMap<TypeId, Box<dyn AnyStorage>>,
+}
+
+trait AnyStorage {
+    fn remove(&mut self, generation: u64) -> Option<Box<dyn Any>>;
+    fn contains(&self, generation: u64) -> bool;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+type Storage<C> = HashMap<u64, Rc<RefCell<C>>>;
+
+impl<C: 'static> AnyStorage for Storage<C> {
+    fn remove(&mut self, generation: u64) -> Option<Box<dyn Any>> {
+        HashMap::remove(self, &generation).map(|component| Box::new(component) as Box<dyn Any>)
+    }
+
+    fn contains(&self, generation: u64) -> bool {
+        self.contains_key(&generation)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl ComponentStore {
+    fn storage<C: 'static>(&self) -> Option<&Storage<C>> {
+        self.storages
+            .get(&TypeId::of::<C>())?
+            .as_any()
+            .downcast_ref()
+    }
+
+    fn storage_mut<C: 'static>(&mut self) -> &mut Storage<C> {
+        self.storages
+            .entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::<Storage<C>>::default())
+            .as_any_mut()
+            .downcast_mut()
+            .unwrap()
+    }
+
+    fn get<C: 'static>(&self, generation: u64) -> Option<Rc<RefCell<C>>> {
+        self.storage::<C>()?.get(&generation).cloned()
+    }
+
+    fn base_object<T: 'static>(&self, generation: u64) -> Option<Rc<T>> {
+        self.base_objects
+            .get(&generation)?
+            .downcast_ref::<Rc<T>>()
+            .cloned()
+    }
+
+    fn remove_base_object_if_empty(&mut self, generation: u64) {
+        if !self
+            .storages
+            .values()
+            .any(|storage| storage.contains(generation))
+        {
+            self.base_objects.remove(&generation);
+        }
+    }
+
+    /// Removes all components of base object, returned values should be dropped after the store is no longer borrowed
+    pub(crate) fn remove_all(&mut self, generation: u64) -> Vec<Box<dyn Any>> {
+        let Some(base_object) = self.base_objects.remove(&generation) else {
+            return vec![];
+        };
+        let mut removed: Vec<_> = self
+            .storages
+            .values_mut()
+            .filter_map(|storage| storage.remove(generation))
+            .collect();
+        removed.push(base_object);
+        removed
+    }
+}
+
+impl Debug for ComponentStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ComponentStore")
+    }
+}
+
+/// Called when base object is removed or destroyed, removed components are returned,
+/// so they can be dropped after borrows of the resource are released
+pub(crate) fn remove_all(generation: u64) -> Vec<Box<dyn Any>> {
+    Resource::with_components_mut(|mut v, _| v.remove_all(generation))
+}
+
+/// Adds component to base object, replacing the previous component of the same type (which is returned).
+/// Components are removed automatically when base object is removed or destroyed.
+///
+/// # Examples
+/// ```rust
+/// struct Job {
+///     kind: JobKind,
+/// }
+///
+/// altv::components::insert(&player, Job { kind: JobKind::Police })?;
+/// ```
+pub fn insert<T: HandleType + ValidBaseObject + 'static, C: 'static>(
+    base_object: &Rc<T>,
+    component: C,
+) -> SomeResult<Option<Rc<RefCell<C>>>> {
+    base_object.assert_valid()?;
+    let generation = base_object.generation();
+
+    let previous = Resource::with_components_mut(|mut v, _| {
+        v.base_objects
+            .entry(generation)
+            .or_insert_with(|| Box::new(base_object.clone()));
+        v.storage_mut::<C>()
+            .insert(generation, Rc::new(RefCell::new(component)))
+    });
+    Ok(previous)
+}
+
+pub fn get<T: HandleType, C: 'static>(base_object: &Rc<T>) -> Option<Rc<RefCell<C>>> {
+    Resource::with_components_ref(|v, _| v.get::<C>(base_object.generation()))
+}
+
+pub fn has<T: HandleType, C: 'static>(base_object: &Rc<T>) -> bool {
+    get::<T, C>(base_object).is_some()
+}
+
+pub fn remove<T: HandleType, C: 'static>(base_object: &Rc<T>) -> Option<Rc<RefCell<C>>> {
+    let generation = base_object.generation();
+    Resource::with_components_mut(|mut v, _| {
+        let removed = v.storage_mut::<C>().remove(&generation);
+        v.remove_base_object_if_empty(generation);
+        removed
+    })
+}
+
+/// Returns all base objects which have every component requested in `Q`.
+///
+/// `Q` is a tuple of:
+/// - base object containers (`PlayerContainer`, `VehicleContainer`, etc.), only base objects of this type are returned
+/// - `&Component` for reading component
+/// - `&mut Component` for mutating component
+///
+/// Note: same component cannot be requested twice (as `&C` and `&mut C`) in one query.
+///
+/// # Examples
+/// ```rust
+/// for (player, job, mut wanted) in &altv::components::query::<(altv::PlayerContainer, &Job, &mut Wanted)>() {
+///     if job.kind != JobKind::Police {
+///         continue;
+///     }
+///     wanted.level = 0;
+///     altv::log!("cleared wanted level of police officer: {}", player.name()?);
+/// }
+/// ```
+pub fn query<Q: QueryParam>() -> Query<Q> {
+    let entries = Resource::with_components_ref(|v, _| {
+        v.base_objects
+            .keys()
+            .filter_map(|generation| Q::fetch(&v, *generation))
+            .collect()
+    });
+    Query {
+        entries,
+        _query: PhantomData,
+    }
+}
+
+/// Result of [`query`](fn.query.html), components are borrowed only while iterating
+pub struct Query<Q: QueryParam> {
+    entries: Vec<Q::Fetched>,
+    _query: PhantomData<fn() -> Q>,
+}
+
+impl<Q: QueryParam> Query<Q> {
+    pub fn iter(&self) -> impl Iterator<Item = Q::Item<'_>> {
+        self.entries.iter().map(Q::borrow)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'q, Q: QueryParam> IntoIterator for &'q Query<Q> {
+    type Item = Q::Item<'q>;
+    type IntoIter =
+        std::iter::Map<std::slice::Iter<'q, Q::Fetched>, fn(&'q Q::Fetched) -> Q::Item<'q>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().map(Q::borrow)
+    }
+}
+
+/// Part of [`query`](fn.query.html) type
+pub trait QueryParam {
+    #[doc(hidden)]
+    type Fetched;
+    type Item<'a>;
+
+    #[doc(hidden)]
+    fn fetch(store: &ComponentStore, generation: u64) -> Option<Self::Fetched>;
+
+    #[doc(hidden)]
+    fn borrow(fetched: &Self::Fetched) -> Self::Item<'_>;
+}
+
+impl<T: HandleType + 'static> QueryParam for Rc<T> {
+    type Fetched = Rc<T>;
+    type Item<'a> = Rc<T>;
+
+    fn fetch(store: &ComponentStore, generation: u64) -> Option<Self::Fetched> {
+        store.base_object(generation)
+    }
+
+    fn borrow(fetched: &Self::Fetched) -> Self::Item<'_> {
+        fetched.clone()
+    }
+}
+
+impl<C: 'static> QueryParam for &C {
+    type Fetched = Rc<RefCell<C>>;
+    type Item<'a> = Ref<'a, C>;
+
+    fn fetch(store: &ComponentStore, generation: u64) -> Option<Self::Fetched> {
+        store.get(generation)
+    }
+
+    fn borrow(fetched: &Self::Fetched) -> Self::Item<'_> {
+        fetched.borrow()
+    }
+}
+
+impl<C: 'static> QueryParam for &mut C {
+    type Fetched = Rc<RefCell<C>>;
+    type Item<'a> = RefMut<'a, C>;
+
+    fn fetch(store: &ComponentStore, generation: u64) -> Option<Self::Fetched> {
+        store.get(generation)
+    }
+
+    fn borrow(fetched: &Self::Fetched) -> Self::Item<'_> {
+        fetched.borrow_mut()
+    }
+}
+
+macro_rules! impl_query_param_tuple {
+    ($( ( $( $name: ident ),+ ) ),+ $(,)?) => { $(
+        #[allow(non_snake_case)]
+        impl<$( $name: QueryParam ),+> QueryParam for ($( $name, )+) {
+            type Fetched = ($( $name::Fetched, )+);
+            type Item<'a> = ($( $name::Item<'a>, )+);
+
+            fn fetch(store: &ComponentStore, generation: u64) -> Option<Self::Fetched> {
+                Some(($( $name::fetch(store, generation)?, )+))
+            }
+
+            fn borrow(($( $name, )+): &Self::Fetched) -> Self::Item<'_> {
+                ($( $name::borrow($name), )+)
+            }
+        }
+    )+ };
+}
+
+impl_query_param_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+);
@@ -1,5 +1,8 @@
+use std::rc::Rc;
+
 use crate::{
-    base_objects::AnyBaseObject,
+    base_object_type_set::BaseObjectTypeSet,
+    base_objects::{network_object, ped, player, vehicle, AnyBaseObject},
     helpers::{self, IntoString},
     sdk,
     vector::Vector3,
@@ -10,7 +13,7 @@ pub fn get_closest_entities(
     range: i32,
     dimension: i32,
     limit: i32,
-    allowed_types: u64,
+    allowed_types: impl Into<BaseObjectTypeSet>,
 ) -> Vec<AnyBaseObject> {
     let pos = pos.into();
     let raw = unsafe {
@@ -21,14 +24,17 @@ pub fn get_closest_entities(
             range,
             dimension,
             limit,
-            allowed_types,
+            allowed_types.into().bits(),
         )
     };
     helpers::read_cpp_base_object_vec(raw)
 }
 
-pub fn get_entities_in_dimension(dimension: i32, allowed_types: u64) -> Vec<AnyBaseObject> {
-    let raw = unsafe { sdk::ICore::GetEntitiesInDimension(dimension, allowed_types) };
+pub fn get_entities_in_dimension(
+    dimension: i32,
+    allowed_types: impl Into<BaseObjectTypeSet>,
+) -> Vec<AnyBaseObject> {
+    let raw = unsafe { sdk::ICore::GetEntitiesInDimension(dimension, allowed_types.into().bits()) };
     helpers::read_cpp_base_object_vec(raw)
 }
 
@@ -36,15 +42,78 @@ pub fn get_entities_in_range(
     pos: impl Into<Vector3>,
     range: i32,
     dimension: i32,
-    allowed_types: u64,
+    allowed_types: impl Into<BaseObjectTypeSet>,
 ) -> Vec<AnyBaseObject> {
     let pos = pos.into();
     let raw = unsafe {
-        sdk::ICore::GetEntitiesInRange(pos.x(), pos.y(), pos.z(), range, dimension, allowed_types)
+        sdk::ICore::GetEntitiesInRange(
+            pos.x(),
+            pos.y(),
+            pos.z(),
+            range,
+            dimension,
+            allowed_types.into().bits(),
+        )
     };
     helpers::read_cpp_base_object_vec(raw)
 }
 
+macro_rules! typed_entity_funcs {
+    ($( $plural: ident, $pool: ident::$name: ident; )+) => { paste::paste! { $(
+        #[doc = "Typed variant of [`get_closest_entities`](fn.get_closest_entities.html) which returns only " $plural]
+        pub fn [<get_closest_ $plural>](
+            pos: impl Into<Vector3>,
+            range: i32,
+            dimension: i32,
+            limit: i32,
+        ) -> Vec<Rc<$pool::$name>> {
+            filter_entities!(
+                get_closest_entities(pos, range, dimension, limit, BaseObjectTypeSet::from_type(altv_sdk::BaseObjectType::$name)),
+                $name
+            )
+        }
+
+        #[doc = "Typed variant of [`get_entities_in_dimension`](fn.get_entities_in_dimension.html) which returns only " $plural]
+        pub fn [<get_ $plural _in_dimension>](dimension: i32) -> Vec<Rc<$pool::$name>> {
+            filter_entities!(
+                get_entities_in_dimension(dimension, BaseObjectTypeSet::from_type(altv_sdk::BaseObjectType::$name)),
+                $name
+            )
+        }
+
+        #[doc = "Typed variant of [`get_entities_in_range`](fn.get_entities_in_range.html) which returns only " $plural]
+        pub fn [<get_ $plural _in_range>](
+            pos: impl Into<Vector3>,
+            range: i32,
+            dimension: i32,
+        ) -> Vec<Rc<$pool::$name>> {
+            filter_entities!(
+                get_entities_in_range(pos, range, dimension, BaseObjectTypeSet::from_type(altv_sdk::BaseObjectType::$name)),
+                $name
+            )
+        }
+    )+ } };
+}
+
+macro_rules! filter_entities {
+    ($base_objects: expr, $name: ident) => {
+        $base_objects
+            .into_iter()
+            .filter_map(|base_object| match base_object {
+                AnyBaseObject::$name(v) => Some(v),
+                _ => None,
+            })
+            .collect()
+    };
+}
+
+typed_entity_funcs!(
+    players, player::Player;
+    vehicles, vehicle::Vehicle;
+    peds, ped::Ped;
+    network_objects, network_object::NetworkObject;
+);
+
 pub fn stop_server() {
     unsafe { sdk::ICore::StopServer() }
 }
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, fmt::Debug, rc::Rc};
+
+use crate::{
+    base_objects::{BasePtr, ValidBaseObject},
+    mvalue::MValue,
+    VoidResult,
+};
+
+use super::{SDKContext, SupportedEventType};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum MetaChangeType {
+    Global,
+    GlobalSynced,
+    Synced,
+    StreamSynced,
+    Local,
+}
+
+impl MetaChangeType {
+    pub fn sdk_event_type(self) -> SupportedEventType {
+        match self {
+            Self::Global => SupportedEventType::GlobalMetaChange,
+            Self::GlobalSynced => SupportedEventType::GlobalSyncedMetaChange,
+            Self::Synced => SupportedEventType::SyncedMetaChange,
+            Self::StreamSynced => SupportedEventType::StreamSyncedMetaChange,
+            Self::Local => SupportedEventType::LocalSyncedMetaChange,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RawMetaChange<'a> {
+    pub old_value: &'a MValue,
+    pub new_value: &'a MValue,
+}
+
+pub type MetaChangeHandler = Box<dyn FnMut(&RawMetaChange) -> VoidResult>;
+
+struct Subscription {
+    /// None for global meta
+    target: Option<Rc<dyn ValidBaseObject>>,
+    handler: MetaChangeHandler,
+}
+
+#[derive(Default)]
+pub struct MetaChangeHandlers {
+    handlers: HashMap<(MetaChangeType, String), Vec<Subscription>>,
+}
+
+impl MetaChangeHandlers {
+    pub fn add(
+        &mut self,
+        meta_type: MetaChangeType,
+        key: String,
+        target: Option<Rc<dyn ValidBaseObject>>,
+        handler: MetaChangeHandler,
+    ) {
+        self.handlers
+            .entry((meta_type, key))
+            .or_default()
+            .push(Subscription { target, handler });
+    }
+
+    /// Removes handlers of destroyed base objects
+    pub fn remove_invalid(&mut self) {
+        self.handlers.retain(|_, subscriptions| {
+            subscriptions.retain(|s| s.target.as_ref().map_or(true, |target| target.valid()));
+            !subscriptions.is_empty()
+        });
+    }
+
+    pub fn handle(&mut self, context: &SDKContext) {
+        let (meta_type, key, target, old_value, new_value) = match context {
+            SDKContext::GlobalMetaChange(c) => (
+                MetaChangeType::Global,
+                &c.key,
+                None,
+                &c.old_value,
+                &c.new_value,
+            ),
+            SDKContext::GlobalSyncedMetaChange(c) => (
+                MetaChangeType::GlobalSynced,
+                &c.key,
+                None,
+                &c.old_value,
+                &c.new_value,
+            ),
+            SDKContext::SyncedMetaChange(c) => (
+                MetaChangeType::Synced,
+                &c.key,
+                c.base_object.base_ptr().ok(),
+                &c.old_value,
+                &c.new_value,
+            ),
+            SDKContext::StreamSyncedMetaChange(c) => (
+                MetaChangeType::StreamSynced,
+                &c.key,
+                c.base_object.base_ptr().ok(),
+                &c.old_value,
+                &c.new_value,
+            ),
+            SDKContext::LocalSyncedMetaChange(c) => (
+                MetaChangeType::Local,
+                &c.key,
+                c.player.base_ptr().ok(),
+                &c.old_value,
+                &c.new_value,
+            ),
+            _ => return,
+        };
+
+        self.remove_invalid();
+
+        let Some(subscriptions) = self.handlers.get_mut(&(meta_type, key.clone())) else {
+            logger::debug!("no meta change handlers for key: {key:?} ({meta_type:?})");
+            return;
+        };
+
+        let change = RawMetaChange {
+            old_value,
+            new_value,
+        };
+
+        for subscription in subscriptions {
+            if let Some(subscription_target) = &subscription.target {
+                if subscription_target.base_ptr().ok() != target {
+                    continue;
+                }
+            }
+
+            if let Err(error) = (subscription.handler)(&change) {
+                logger::error!(
+                    "handler of meta change: {key:?} ({meta_type:?}) failed with error: {error:?}"
+                );
+            }
+        }
+    }
+}
+
+impl Debug for MetaChangeHandlers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MetaChangeHandlers {{ keys: {} }}", self.handlers.len())
+    }
+}
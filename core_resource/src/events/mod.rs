@@ -8,6 +8,7 @@ pub mod custom_contexts;
 pub(self) mod helpers;
 pub(self) mod cancellable;
 pub(crate) mod connection_queue;
+pub(crate) mod meta_change;
 pub mod structs;
 
 macro_rules! log_user_handler_error {
@@ -288,6 +289,7 @@ custom_events!(
 pub struct EventManager {
     user_sdk_handlers: HashMap<SupportedEventType, Vec<SDKHandler>>,
     user_custom_handlers: HashMap<CustomEventType, Vec<CustomHandler>>,
+    meta_change_handlers: meta_change::MetaChangeHandlers,
 }
 
 impl EventManager {
@@ -311,6 +313,8 @@ impl EventManager {
         } else {
             logger::debug!("no user sdk handlers for event: {event_type:?}");
         }
+
+        self.meta_change_handlers.handle(&context);
         
         self.handle_custom_event_type(event_type, context, resource);
     }
@@ -358,6 +362,22 @@ impl EventManager {
         self.toggle_sdk_event(custom_event_type.into(), true);
     }
 
+    pub fn add_meta_change_handler(
+        &mut self,
+        meta_type: meta_change::MetaChangeType,
+        key: String,
+        target: Option<std::rc::Rc<dyn crate::base_objects::ValidBaseObject>>,
+        handler: meta_change::MetaChangeHandler,
+    ) {
+        self.meta_change_handlers.add(meta_type, key, target, handler);
+
+        self.toggle_sdk_event(meta_type.sdk_event_type(), true);
+    }
+
+    pub fn remove_invalid_meta_change_handlers(&mut self) {
+        self.meta_change_handlers.remove_invalid();
+    }
+
     fn toggle_sdk_event(&self, event_type: SupportedEventType, state: bool) {
         Resource::with(|r| {
             (r.module_handlers.toggle_event_type)(
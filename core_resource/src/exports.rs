@@ -1,37 +1,48 @@
 pub use anyhow;
 pub use core_shared::*;
+pub use serde_json;
 
 pub use crate::{
     alt_resource::AltResource as Resource,
+    base_object_type_set::BaseObjectTypeSet,
     base_objects::{
-        blip::{Blip, BlipContainer},
-        checkpoint::{Checkpoint, CheckpointContainer},
-        col_shape::{ColShape, ColShapeContainer},
-        extra_pools::{AnyEntity, Entity, SyncId},
-        marker::{Marker, MarkerContainer},
-        network_object::{NetworkObject, NetworkObjectContainer},
-        ped::{Ped, PedContainer},
-        player::{Player, PlayerContainer},
-        vehicle::{Vehicle, VehicleContainer},
-        virtual_entity::{VirtualEntity, VirtualEntityContainer},
-        virtual_entity_group::{VirtualEntityGroup, VirtualEntityGroupContainer},
-        voice_channel::{VoiceChannel, VoiceChannelContainer},
-        BaseObjectWrapper, ValidBaseObject,
+        blip::{Blip, BlipContainer, WeakBlip},
+        checkpoint::{Checkpoint, CheckpointContainer, WeakCheckpoint},
+        col_shape::{ColShape, ColShapeContainer, WeakColShape},
+        extra_pools::{AnyEntity, AnyWorldObject, Entity, SyncId},
+        marker::{Marker, MarkerContainer, WeakMarker},
+        network_object::{NetworkObject, NetworkObjectContainer, WeakNetworkObject},
+        ped::{Ped, PedContainer, WeakPed},
+        player::{Player, PlayerContainer, WeakPlayer},
+        vehicle::{Vehicle, VehicleContainer, WeakVehicle},
+        virtual_entity::{VirtualEntity, VirtualEntityContainer, WeakVirtualEntity},
+        virtual_entity_group::{
+            VirtualEntityGroup, VirtualEntityGroupContainer, WeakVirtualEntityGroup,
+        },
+        voice_channel::{VoiceChannel, VoiceChannelContainer, WeakVoiceChannel},
+        AnyBaseObject, BaseObjectWrapper, ValidBaseObject, Weak,
     },
     col_shape::ColShapy,
+    handle::{Handle, HandleType},
     helpers::{hash, Hash},
     init,
+    loadout::{weapon_name, Loadout, LoadoutWeapon},
     ped_model_info::PedModelInfo,
+    player_appearance::PlayerAppearance,
     quaternion::Quaternion,
     result::{IntoVoidResult, SomeResult, VoidResult},
-    rgba::RGBA,
+    rgba::{Hsl, Hsv, IntoRGBA, RGBA},
     structs::{
         AnimationFlags, AttachToEntityBoneIndex, AttachToEntityBoneName, PlayAnimation,
-        PlayerDateTime, PlayerHeadBlendData,
+        PlayerDateTime, PlayerDlcCloth, PlayerDlcProp, PlayerHeadBlendData, PlayerHeadOverlay,
+        VehicleNeon,
     },
+    stop_hook::set_stop_hook,
     timers::create_timer,
     vector::{Vector2, Vector3},
     vehicle_model_info::VehicleModelInfo,
+    vehicle_snapshot::VehicleSnapshot,
+    vehicle_tuning::{Tuning, TuningMod, VehicleMod},
     world_object::WorldObject,
 };
 
@@ -237,7 +248,9 @@ pub mod mvalue {
         pub use crate::mvalue::{serialize_mvalue, Serializable};
     }
 
-    pub use crate::mvalue::{MValue, MValueList};
+    pub use crate::mvalue::{
+        compression, ByteArray, FromMValue, MValue, MValueFunction, MValueList,
+    };
 
     #[macro_export]
     macro_rules! __mvalue_list {
@@ -291,11 +304,29 @@ pub mod config_node {
 pub mod meta {
     pub use crate::meta::{
         base_object::{entry::*, normal_meta::*, synced_meta::*},
+        change::{on_change, on_synced_change, MetaChange},
         checkpoint_stream_synced_meta::*,
         entity_stream_synced_meta::*,
         entry::*,
         global::*,
         player_local_meta::*,
+        snapshot::MetaSnapshot,
+        typed::*,
         ve_stream_synced_meta::*,
     };
 }
+
+pub mod commands {
+    pub use crate::commands::{
+        add, set_permissions, CommandArg, CommandContext, CommandOptions, CommandPermissions,
+        CHAT_MESSAGE_EVENT, COMMAND_PREFIX,
+    };
+}
+
+pub mod components {
+    pub use crate::components::{get, has, insert, query, remove, Query, QueryParam};
+}
+
+pub mod spatial {
+    pub use crate::spatial::{closest, in_box, in_range, SpatialEntity};
+}
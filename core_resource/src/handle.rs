@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use crate::{
+    base_objects::{
+        blip, checkpoint, col_shape, extra_pools::Entity, generation_epoch, marker, network_object,
+        ped, player, vehicle, virtual_entity, virtual_entity_group, voice_channel,
+        BaseObjectWrapper,
+    },
+    col_shape::ColShapy,
+    mvalue::{FromMValue, MValue, Serializable},
+    resource::Resource,
+    SomeResult,
+};
+
+/// Base object types which can be referenced by [`Handle`](struct.Handle.html)
+pub trait HandleType: Sized {
+    const TYPE: altv_sdk::BaseObjectType;
+    /// Name of the type used in mvalue of handle
+    const NAME: &'static str;
+
+    #[doc(hidden)]
+    fn raw_id(&self) -> SomeResult<u32>;
+
+    #[doc(hidden)]
+    fn generation(&self) -> u64;
+
+    #[doc(hidden)]
+    fn get_by_generation(generation: u64) -> Option<Rc<Self>>;
+}
+
+/// Copyable reference to base object which can be stored anywhere
+/// (`HashMap` key, database row, mvalue) and resolved back to the base object later.
+///
+/// Handle consists of base object type (`T`), id, epoch and generation.
+/// Ids can be reused by alt:V after base object is destroyed but generations can't,
+/// so handle of destroyed base object always resolves to `None`, even if its id is taken by a new one.
+/// Epoch is different every time resource is loaded, so handles saved before restart resolve to `None` too.
+///
+/// Note: generations are unique only in the resource which created the handle,
+/// use base objects themselves when communicating with other resources.
+///
+/// With `serde` feature handle implements `Serialize` and `Deserialize`.
+///
+/// # Examples
+/// ```rust
+/// let handle: altv::Handle<altv::Player> = player.handle()?;
+/// let mut scores = std::collections::HashMap::new();
+/// scores.insert(handle, 100);
+///
+/// altv::set_timeout(
+///     move || {
+///         if let Some(player) = handle.resolve() {
+///             altv::log!("player {} is still here", player.name()?);
+///         }
+///         Ok(())
+///     },
+///     1000,
+/// );
+/// ```
+pub struct Handle<T: HandleType> {
+    id: u32,
+    epoch: u64,
+    generation: u64,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: HandleType> Handle<T> {
+    /// Returns base object if it still exists
+    pub fn resolve(&self) -> Option<Rc<T>> {
+        if self.epoch != generation_epoch() {
+            return None;
+        }
+        let base_object = T::get_by_generation(self.generation)?;
+        (base_object.raw_id().ok() == Some(self.id)).then_some(base_object)
+    }
+
+    /// alt:V id of the base object
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Random number which is different every time resource is loaded
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn base_object_type(&self) -> altv_sdk::BaseObjectType {
+        T::TYPE
+    }
+}
+
+impl<T, InheritPtrs: Clone> BaseObjectWrapper<T, InheritPtrs>
+where
+    Self: HandleType,
+{
+    /// Creates [`Handle`](struct.Handle.html) of base object, returns error if base object is destroyed
+    pub fn handle(&self) -> SomeResult<Handle<Self>> {
+        Ok(Handle {
+            id: self.raw_id()?,
+            epoch: generation_epoch(),
+            generation: HandleType::generation(self),
+            _type: PhantomData,
+        })
+    }
+}
+
+impl<T: HandleType> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: HandleType> Copy for Handle<T> {}
+
+impl<T: HandleType> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.epoch == other.epoch && self.generation == other.generation
+    }
+}
+
+impl<T: HandleType> Eq for Handle<T> {}
+
+impl<T: HandleType> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.epoch.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T: HandleType> Debug for Handle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Handle")
+            .field("type", &T::TYPE)
+            .field("id", &self.id)
+            .field("epoch", &self.epoch)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+/// Handle is converted to dict: `{ type: "Player", id: 1, epoch: 123, generation: 2 }`
+impl<T: HandleType> TryFrom<Handle<T>> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: Handle<T>) -> SomeResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl<T: HandleType> TryFrom<&Handle<T>> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &Handle<T>) -> SomeResult<Self> {
+        HashMap::from([
+            ("type".to_string(), Serializable::try_from(T::NAME)?),
+            ("id".to_string(), Serializable::try_from(value.id)?),
+            ("epoch".to_string(), Serializable::try_from(value.epoch)?),
+            (
+                "generation".to_string(),
+                Serializable::try_from(value.generation)?,
+            ),
+        ])
+        .try_into()
+    }
+}
+
+impl<T: HandleType> FromMValue for Handle<T> {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        let MValue::Dict(dict) = value else {
+            anyhow::bail!("Expected mvalue of type: Handle (dict), received: {value:?}");
+        };
+        match dict.get("type") {
+            Some(MValue::String(name)) if name == T::NAME => {}
+            name => anyhow::bail!("Expected handle of type: {}, received: {name:?}", T::NAME),
+        }
+        let (Some(id), Some(epoch), Some(generation)) =
+            (dict.get("id"), dict.get("epoch"), dict.get("generation"))
+        else {
+            anyhow::bail!("Expected handle with id, epoch and generation, received: {value:?}");
+        };
+
+        Ok(Self {
+            id: u32::from_mvalue(id)?,
+            epoch: u64::from_mvalue(epoch)?,
+            generation: u64::from_mvalue(generation)?,
+            _type: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: HandleType> serde::Serialize for Handle<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Handle", 4)?;
+        state.serialize_field("type", T::NAME)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("epoch", &self.epoch)?;
+        state.serialize_field("generation", &self.generation)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerdeHandle {
+    #[serde(rename = "type")]
+    name: String,
+    id: u32,
+    epoch: u64,
+    generation: u64,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: HandleType> serde::Deserialize<'de> for Handle<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let handle = SerdeHandle::deserialize(deserializer)?;
+        if handle.name != T::NAME {
+            return Err(serde::de::Error::custom(format!(
+                "Expected handle of type: {}, received: {}",
+                T::NAME,
+                handle.name
+            )));
+        }
+        Ok(Self {
+            id: handle.id,
+            epoch: handle.epoch,
+            generation: handle.generation,
+            _type: PhantomData,
+        })
+    }
+}
+
+macro_rules! impl_handle_type {
+    ($( $name: ident, $pool: ident, $base_type: ident, $raw_id: expr; )+) => { $(
+        impl HandleType for $pool::$name {
+            const TYPE: altv_sdk::BaseObjectType = altv_sdk::BaseObjectType::$base_type;
+            const NAME: &'static str = stringify!($name);
+
+            fn raw_id(&self) -> SomeResult<u32> {
+                $raw_id(self)
+            }
+
+            fn generation(&self) -> u64 {
+                self.generation()
+            }
+
+            fn get_by_generation(generation: u64) -> Option<Rc<Self>> {
+                Resource::with_base_objects_ref(|v, _| v.$pool.get_by_generation(generation))
+            }
+        }
+    )+ };
+}
+
+impl_handle_type!(
+    ColShape, col_shape, Colshape, |v: &col_shape::ColShape| ColShapy::id(v);
+    Vehicle, vehicle, Vehicle, |v: &vehicle::Vehicle| v.id();
+    Player, player, Player, |v: &player::Player| v.id();
+    Ped, ped, Ped, |v: &ped::Ped| v.id();
+    NetworkObject, network_object, NetworkObject, |v: &network_object::NetworkObject| v.id();
+    VirtualEntity, virtual_entity, VirtualEntity, |v: &virtual_entity::VirtualEntity| v.id();
+    VirtualEntityGroup, virtual_entity_group, VirtualEntityGroup,
+        |v: &virtual_entity_group::VirtualEntityGroup| v.id();
+    Blip, blip, Blip, |v: &blip::Blip| v.id();
+    VoiceChannel, voice_channel, VoiceChannel, |v: &voice_channel::VoiceChannel| v.id();
+    Marker, marker, Marker, |v: &marker::Marker| v.id();
+    Checkpoint, checkpoint, Checkpoint, |v: &checkpoint::Checkpoint| ColShapy::id(v);
+);
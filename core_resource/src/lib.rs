@@ -5,15 +5,20 @@ use altv_sdk::ffi as sdk;
 mod resource;
 use resource::Resource;
 mod alt_resource;
+mod base_object_type_set;
 mod base_objects;
 mod blip;
 mod checkpoint;
 mod client_events;
 mod col_shape;
+mod commands;
+mod components;
 mod config_node;
 mod core_funcs;
 mod events;
+mod handle;
 mod helpers;
+mod loadout;
 mod logging;
 mod marker;
 mod meta;
@@ -22,14 +27,19 @@ mod network_object;
 mod ped;
 mod ped_model_info;
 mod player;
+mod player_appearance;
 mod quaternion;
 mod rgba;
 mod script_events;
+mod spatial;
+mod stop_hook;
 mod structs;
 mod timers;
 mod vector;
 mod vehicle;
 mod vehicle_model_info;
+mod vehicle_snapshot;
+mod vehicle_tuning;
 mod virtual_entities;
 mod voice_channel;
 mod world_object;
@@ -37,6 +47,16 @@ mod world_object;
 mod result;
 pub use result::{IntoVoidResult, SomeResult, VoidResult};
 
+// used by mvalue_derive macros in this crate (`#[mvalue(crate_name = "crate")]`)
+#[doc(hidden)]
+pub mod __internal {
+    pub use crate::{
+        mvalue::{FromMValue, MValue, Serializable},
+        SomeResult,
+    };
+    pub use anyhow;
+}
+
 pub mod exports;
 
 pub fn init(
@@ -61,6 +81,9 @@ pub fn init(
         Resource::with_timers_mut(|mut timers, resource| {
             timers.process_timers(resource.timer_schedule.borrow_mut());
         });
+        Resource::with_spatial_index_mut(|mut index, resource| {
+            index.refresh(&resource.extra_base_object_pools.borrow().entity);
+        });
     });
 
     set_callback!(on_sdk_event, |event_type, event| {
@@ -81,6 +104,12 @@ pub fn init(
         });
     });
 
+    set_callback!(on_stop, || {
+        Resource::with(|resource| {
+            resource.on_stop();
+        });
+    });
+
     script_events::LocalEventManager::init();
     script_events::ClientEventManager::init();
 }
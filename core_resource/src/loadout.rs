@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+
+use mvalue_derive::{FromMValue, IntoMValue};
+
+use crate::{
+    base_objects::player,
+    helpers::{hash, Hash, IntoHash},
+    SomeResult, VoidResult,
+};
+
+/// Weapons of player with their components, tints and currently selected weapon.
+///
+/// Can be converted to MValue and back, so it can be sent to client, stored in meta or saved as JSON.
+///
+/// # Examples
+/// ```rust
+/// let loadout = altv::Loadout {
+///     weapons: vec![
+///         altv::LoadoutWeapon::new("weapon_pistol", 100),
+///         altv::LoadoutWeapon {
+///             components: vec![altv::hash("component_at_ar_flsh")],
+///             tint_index: 2,
+///             ..altv::LoadoutWeapon::new("weapon_carbinerifle", 300)
+///         },
+///     ],
+///     current_weapon: Some(altv::hash("weapon_pistol")),
+/// };
+/// player.set_loadout(&loadout)?;
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct Loadout {
+    pub weapons: Vec<LoadoutWeapon>,
+    /// `None` means that current weapon is not changed when loadout is applied
+    #[mvalue(default)]
+    pub current_weapon: Option<Hash>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct LoadoutWeapon {
+    pub hash: Hash,
+    /// Ammo given when weapon is added to player.
+    /// Server doesn't know current ammo of weapons, so it's `None` in [`loadout()`](struct.BaseObjectWrapper.html#method.loadout)
+    /// and ammo of weapons which player already has is not changed.
+    #[mvalue(default)]
+    pub ammo: Option<i32>,
+    #[mvalue(default)]
+    pub components: Vec<Hash>,
+    #[mvalue(default)]
+    pub tint_index: u8,
+}
+
+impl LoadoutWeapon {
+    pub fn new(weapon: impl IntoHash, ammo: i32) -> Self {
+        Self {
+            hash: weapon.into_hash(),
+            ammo: Some(ammo),
+            ..Default::default()
+        }
+    }
+
+    /// Name of weapon, see [`weapon_name`](fn.weapon_name.html)
+    pub fn name(&self) -> Option<&'static str> {
+        weapon_name(self.hash)
+    }
+}
+
+/// # **`Player loadout implementation`**
+impl player::Player {
+    /// Reads weapons, their components and tints and currently selected weapon
+    pub fn loadout(&self) -> SomeResult<Loadout> {
+        let weapons = self
+            .weapons()?
+            .into_iter()
+            .map(|weapon| LoadoutWeapon {
+                hash: weapon.hash,
+                ammo: None,
+                components: weapon.components,
+                tint_index: weapon.tint_index,
+            })
+            .collect();
+
+        Ok(Loadout {
+            weapons,
+            current_weapon: Some(self.current_weapon()?),
+        })
+    }
+
+    /// Makes weapons of player the same as in [`Loadout`](struct.Loadout.html),
+    /// only the differences are applied: missing weapons are given, extra weapons are removed,
+    /// components and tints are changed only if they are different
+    pub fn set_loadout(&self, loadout: &Loadout) -> VoidResult {
+        let current = self.weapons()?;
+
+        let target_hashes: HashSet<Hash> = loadout.weapons.iter().map(|w| w.hash).collect();
+        for weapon in &current {
+            if !target_hashes.contains(&weapon.hash) {
+                self.remove_weapon(weapon.hash)?;
+            }
+        }
+
+        for target in &loadout.weapons {
+            let existing = current.iter().find(|weapon| weapon.hash == target.hash);
+
+            let (components, tint_index) = match existing {
+                Some(weapon) => (weapon.components.as_slice(), weapon.tint_index),
+                None => {
+                    self.give_weapon(target.hash, target.ammo.unwrap_or(0), false)?;
+                    (&[][..], 0)
+                }
+            };
+
+            for component in components {
+                if !target.components.contains(component) {
+                    self.remove_weapon_component(target.hash, *component)?;
+                }
+            }
+            for component in &target.components {
+                if !components.contains(component) {
+                    self.add_weapon_component(target.hash, *component)?;
+                }
+            }
+
+            if existing.is_none() || tint_index != target.tint_index {
+                self.set_weapon_tint_index(target.hash, target.tint_index)?;
+            }
+        }
+
+        if let Some(weapon) = loadout.current_weapon {
+            if self.current_weapon()? != weapon {
+                self.set_current_weapon(weapon)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns name of GTA weapon (`"weapon_pistol"`) by its hash, `None` if weapon is unknown
+///
+/// # Examples
+/// ```rust
+/// for weapon in player.weapons()? {
+///     altv::log!("{}", altv::weapon_name(weapon.hash).unwrap_or("unknown"));
+/// }
+/// ```
+pub fn weapon_name(weapon: Hash) -> Option<&'static str> {
+    WEAPON_NAMES_BY_HASH.with(|names| names.get(&weapon).copied())
+}
+
+thread_local! {
+    static WEAPON_NAMES_BY_HASH: HashMap<Hash, &'static str> =
+        WEAPON_NAMES.iter().map(|name| (hash(name), *name)).collect();
+}
+
+/// Names of GTA weapons (lowercase, because that's what joaat hashes of weapons are calculated from)
+const WEAPON_NAMES: &[&str] = &[
+    // melee
+    "weapon_unarmed",
+    "weapon_dagger",
+    "weapon_bat",
+    "weapon_bottle",
+    "weapon_crowbar",
+    "weapon_flashlight",
+    "weapon_golfclub",
+    "weapon_hammer",
+    "weapon_hatchet",
+    "weapon_knuckle",
+    "weapon_knife",
+    "weapon_machete",
+    "weapon_switchblade",
+    "weapon_nightstick",
+    "weapon_wrench",
+    "weapon_battleaxe",
+    "weapon_poolcue",
+    "weapon_stone_hatchet",
+    "weapon_candycane",
+    // handguns
+    "weapon_pistol",
+    "weapon_pistol_mk2",
+    "weapon_combatpistol",
+    "weapon_appistol",
+    "weapon_stungun",
+    "weapon_pistol50",
+    "weapon_snspistol",
+    "weapon_snspistol_mk2",
+    "weapon_heavypistol",
+    "weapon_vintagepistol",
+    "weapon_flaregun",
+    "weapon_marksmanpistol",
+    "weapon_revolver",
+    "weapon_revolver_mk2",
+    "weapon_doubleaction",
+    "weapon_raypistol",
+    "weapon_ceramicpistol",
+    "weapon_navyrevolver",
+    "weapon_gadgetpistol",
+    "weapon_stungun_mp",
+    "weapon_pistolxm3",
+    // submachine guns
+    "weapon_microsmg",
+    "weapon_smg",
+    "weapon_smg_mk2",
+    "weapon_assaultsmg",
+    "weapon_combatpdw",
+    "weapon_machinepistol",
+    "weapon_minismg",
+    "weapon_raycarbine",
+    "weapon_tecpistol",
+    // shotguns
+    "weapon_pumpshotgun",
+    "weapon_pumpshotgun_mk2",
+    "weapon_sawnoffshotgun",
+    "weapon_assaultshotgun",
+    "weapon_bullpupshotgun",
+    "weapon_musket",
+    "weapon_heavyshotgun",
+    "weapon_dbshotgun",
+    "weapon_autoshotgun",
+    "weapon_combatshotgun",
+    // assault rifles
+    "weapon_assaultrifle",
+    "weapon_assaultrifle_mk2",
+    "weapon_carbinerifle",
+    "weapon_carbinerifle_mk2",
+    "weapon_advancedrifle",
+    "weapon_specialcarbine",
+    "weapon_specialcarbine_mk2",
+    "weapon_bullpuprifle",
+    "weapon_bullpuprifle_mk2",
+    "weapon_compactrifle",
+    "weapon_militaryrifle",
+    "weapon_heavyrifle",
+    "weapon_tacticalrifle",
+    // light machine guns
+    "weapon_mg",
+    "weapon_combatmg",
+    "weapon_combatmg_mk2",
+    "weapon_gusenberg",
+    // sniper rifles
+    "weapon_sniperrifle",
+    "weapon_heavysniper",
+    "weapon_heavysniper_mk2",
+    "weapon_marksmanrifle",
+    "weapon_marksmanrifle_mk2",
+    "weapon_precisionrifle",
+    // heavy weapons
+    "weapon_rpg",
+    "weapon_grenadelauncher",
+    "weapon_grenadelauncher_smoke",
+    "weapon_minigun",
+    "weapon_firework",
+    "weapon_railgun",
+    "weapon_hominglauncher",
+    "weapon_compactlauncher",
+    "weapon_rayminigun",
+    "weapon_emplauncher",
+    "weapon_railgunxm3",
+    // throwables
+    "weapon_grenade",
+    "weapon_bzgas",
+    "weapon_molotov",
+    "weapon_stickybomb",
+    "weapon_proxmine",
+    "weapon_snowball",
+    "weapon_pipebomb",
+    "weapon_ball",
+    "weapon_smokegrenade",
+    "weapon_flare",
+    "weapon_acidpackage",
+    // miscellaneous
+    "weapon_petrolcan",
+    "gadget_parachute",
+    "weapon_fireextinguisher",
+    "weapon_hazardcan",
+    "weapon_fertilizercan",
+];
@@ -1,7 +1,7 @@
 use crate::{
     base_objects::{marker, player},
     helpers,
-    rgba::RGBA,
+    rgba::{IntoRGBA, RGBA},
     sdk,
     vector::Vector3,
     SomeResult, VoidResult,
@@ -15,10 +15,10 @@ impl marker::Marker {
     pub fn new(
         marker_type: altv_sdk::MarkerType,
         pos: impl Into<Vector3>,
-        color: impl Into<RGBA>,
-    ) -> marker::MarkerContainer {
+        color: impl IntoRGBA,
+    ) -> SomeResult<marker::MarkerContainer> {
         let pos = pos.into();
-        let color = color.into();
+        let color = color.into_rgba()?;
 
         let ptr = unsafe {
             sdk::ICore::CreateMarker(
@@ -35,7 +35,7 @@ impl marker::Marker {
             )
         };
 
-        marker::add_to_pool!(NonNull::new(ptr).unwrap())
+        Ok(marker::add_to_pool!(NonNull::new(ptr).unwrap()))
     }
 
     pub fn destroy(&self) -> VoidResult {
@@ -61,8 +61,8 @@ impl marker::Marker {
         ))
     }
 
-    pub fn set_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color = color.into();
+    pub fn set_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
 
         unsafe {
             sdk::IMarker::SetColor(self.raw_ptr()?, color.r(), color.g(), color.b(), color.a())
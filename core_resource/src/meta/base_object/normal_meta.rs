@@ -3,7 +3,11 @@ use std::rc::Rc;
 use crate::{
     base_objects::{BaseObjectContainer, BasePtr, ValidBaseObject},
     helpers::{self, IntoString},
-    sdk, SomeResult,
+    meta::{
+        snapshot::MetaSnapshot,
+        typed::{MetaKey, TypedMetaEntry},
+    },
+    sdk, SomeResult, VoidResult,
 };
 
 pub struct NormalBaseObjectMetaEntry<T, InheritPtrs: Clone> {
@@ -38,10 +42,29 @@ where
         })
     }
 
+    /// Same as [`meta_entry`](#method.meta_entry), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+    fn typed_meta_entry<V>(
+        self: &Rc<Self>,
+        key: MetaKey<V>,
+    ) -> SomeResult<TypedMetaEntry<NormalBaseObjectMetaEntry<T, InheritPtrs>, V>> {
+        Ok(TypedMetaEntry::new(self.meta_entry(key)?))
+    }
+
     fn meta_keys(self: &Rc<Self>) -> SomeResult<Vec<String>> {
         let base_object: BaseObjectContainer<T, InheritPtrs> = self.clone().into();
         Ok(helpers::read_cpp_str_vec(unsafe {
             sdk::IBaseObject::GetMetaDataKeys(base_object.raw_base_ptr()?)
         }))
     }
+
+    /// Copies all keys and values of normal meta of this object,
+    /// see [`MetaSnapshot`](struct.MetaSnapshot.html).
+    fn meta_snapshot(self: &Rc<Self>) -> SomeResult<MetaSnapshot> {
+        MetaSnapshot::take(self.meta_keys()?, |key| self.meta_entry(key))
+    }
+
+    /// Replaces normal meta of this object with the snapshot, keys which are not in the snapshot are deleted.
+    fn apply_meta_snapshot(self: &Rc<Self>, snapshot: &MetaSnapshot) -> VoidResult {
+        snapshot.apply(self.meta_keys()?, |key| self.meta_entry(key))
+    }
 }
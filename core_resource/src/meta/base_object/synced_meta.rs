@@ -2,8 +2,15 @@ use std::rc::Rc;
 
 use crate::{
     base_objects::{BaseObjectContainer, BasePtr, ValidBaseObject},
+    events::meta_change::MetaChangeType,
     helpers::{self, IntoString},
-    sdk, SomeResult,
+    meta::{
+        change::{self, MetaChange},
+        snapshot::MetaSnapshot,
+        typed::{MetaKey, TypedMetaEntry},
+    },
+    mvalue::FromMValue,
+    sdk, IntoVoidResult, SomeResult, VoidResult,
 };
 
 pub struct SyncedBaseObjectMetaEntry<T, InheritPtrs: Clone> {
@@ -38,10 +45,48 @@ where
         })
     }
 
+    /// Same as [`synced_meta_entry`](#method.synced_meta_entry), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+    fn typed_synced_meta_entry<V>(
+        self: &Rc<Self>,
+        key: MetaKey<V>,
+    ) -> SomeResult<TypedMetaEntry<SyncedBaseObjectMetaEntry<T, InheritPtrs>, V>> {
+        Ok(TypedMetaEntry::new(self.synced_meta_entry(key)?))
+    }
+
+    /// Subscribes to changes of specific key of synced meta of this object,
+    /// handler is removed when object is destroyed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// object.on_synced_meta_change("example", |change: &altv::meta::MetaChange<i64>| {
+    ///     altv::log!("changed from {:?} to {:?}", change.old_value, change.new_value);
+    /// });
+    /// ```
+    fn on_synced_meta_change<V: FromMValue, R: IntoVoidResult>(
+        self: &Rc<Self>,
+        key: impl IntoString,
+        handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+    ) where
+        Self: Sized + 'static,
+    {
+        change::add_handler(MetaChangeType::Synced, key, Some(self.clone()), handler);
+    }
+
     fn synced_meta_keys(self: &Rc<Self>) -> SomeResult<Vec<String>> {
         let base_object: BaseObjectContainer<T, InheritPtrs> = self.clone().into();
         Ok(helpers::read_cpp_str_vec(unsafe {
             sdk::IBaseObject::GetSyncedMetaDataKeys(base_object.raw_base_ptr()?)
         }))
     }
+
+    /// Copies all keys and values of synced meta of this object,
+    /// see [`MetaSnapshot`](struct.MetaSnapshot.html).
+    fn synced_meta_snapshot(self: &Rc<Self>) -> SomeResult<MetaSnapshot> {
+        MetaSnapshot::take(self.synced_meta_keys()?, |key| self.synced_meta_entry(key))
+    }
+
+    /// Replaces synced meta of this object with the snapshot, keys which are not in the snapshot are deleted.
+    fn apply_synced_meta_snapshot(self: &Rc<Self>, snapshot: &MetaSnapshot) -> VoidResult {
+        snapshot.apply(self.synced_meta_keys()?, |key| self.synced_meta_entry(key))
+    }
 }
@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use crate::{
+    base_objects::ValidBaseObject,
+    events::meta_change::{MetaChangeType, RawMetaChange},
+    helpers::IntoString,
+    mvalue::FromMValue,
+    resource::Resource,
+    IntoVoidResult,
+};
+
+#[derive(Debug)]
+pub struct MetaChange<V> {
+    /// `None` if key did not exist before
+    pub old_value: Option<V>,
+    /// `None` if key was deleted
+    pub new_value: Option<V>,
+}
+
+pub(crate) fn add_handler<V: FromMValue, R: IntoVoidResult>(
+    meta_type: MetaChangeType,
+    key: impl IntoString,
+    target: Option<Rc<dyn ValidBaseObject>>,
+    mut handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+) {
+    let handler = Box::new(move |change: &RawMetaChange| {
+        let change = MetaChange {
+            old_value: Option::<V>::from_mvalue(change.old_value)?,
+            new_value: Option::<V>::from_mvalue(change.new_value)?,
+        };
+        handler(&change).into_void_result()
+    });
+
+    Resource::with_events_mut(|mut events, _| {
+        events.add_meta_change_handler(meta_type, key.into_string(), target, handler);
+    });
+}
+
+/// Subscribes to changes of specific key of global meta,
+/// values are converted to `V` (see [`FromMValue`](../mvalue/trait.FromMValue.html)).
+///
+/// # Examples
+/// ```rust
+/// altv::meta::on_change("weather", |change: &altv::meta::MetaChange<u32>| {
+///     altv::log!("weather changed from {:?} to {:?}", change.old_value, change.new_value);
+/// });
+/// ```
+pub fn on_change<V: FromMValue, R: IntoVoidResult>(
+    key: impl IntoString,
+    handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+) {
+    add_handler(MetaChangeType::Global, key, None, handler);
+}
+
+/// Same as [`on_change`](fn.on_change.html), but for global **synced** meta
+pub fn on_synced_change<V: FromMValue, R: IntoVoidResult>(
+    key: impl IntoString,
+    handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+) {
+    add_handler(MetaChangeType::GlobalSynced, key, None, handler);
+}
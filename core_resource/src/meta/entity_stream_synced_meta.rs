@@ -1,10 +1,16 @@
 use std::rc::Rc;
 
 use crate::{
-    sdk,
     base_objects::{extra_pools::AnyEntity, ValidBaseObject},
+    events::meta_change::MetaChangeType,
     helpers::{self, IntoString},
-    SomeResult,
+    meta::{
+        change::{self, MetaChange},
+        snapshot::MetaSnapshot,
+        typed::{MetaKey, TypedMetaEntry},
+    },
+    mvalue::FromMValue,
+    sdk, IntoVoidResult, SomeResult, VoidResult,
 };
 
 pub struct StreamSyncedEntityMetaEntry {
@@ -39,10 +45,57 @@ where
         })
     }
 
+    /// Same as [`stream_synced_meta_entry`](#method.stream_synced_meta_entry), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+    fn typed_stream_synced_meta_entry<V>(
+        self: &Rc<Self>,
+        key: MetaKey<V>,
+    ) -> SomeResult<TypedMetaEntry<StreamSyncedEntityMetaEntry, V>> {
+        Ok(TypedMetaEntry::new(self.stream_synced_meta_entry(key)?))
+    }
+
+    /// Subscribes to changes of specific key of stream synced meta of this object,
+    /// handler is removed when object is destroyed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// object.on_stream_synced_meta_change("example", |change: &altv::meta::MetaChange<i64>| {
+    ///     altv::log!("changed from {:?} to {:?}", change.old_value, change.new_value);
+    /// });
+    /// ```
+    fn on_stream_synced_meta_change<V: FromMValue, R: IntoVoidResult>(
+        self: &Rc<Self>,
+        key: impl IntoString,
+        handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+    ) where
+        Self: Sized + 'static,
+    {
+        change::add_handler(
+            MetaChangeType::StreamSynced,
+            key,
+            Some(self.clone()),
+            handler,
+        );
+    }
+
     fn stream_synced_meta_keys(self: &Rc<Self>) -> SomeResult<Vec<String>> {
         let entity: AnyEntity = self.clone().into();
         Ok(helpers::read_cpp_str_vec(unsafe {
             sdk::IEntity::GetStreamSyncedMetaDataKeys(entity.raw_ptr()?)
         }))
     }
+
+    /// Copies all keys and values of stream synced meta of this object,
+    /// see [`MetaSnapshot`](struct.MetaSnapshot.html).
+    fn stream_synced_meta_snapshot(self: &Rc<Self>) -> SomeResult<MetaSnapshot> {
+        MetaSnapshot::take(self.stream_synced_meta_keys()?, |key| {
+            self.stream_synced_meta_entry(key)
+        })
+    }
+
+    /// Replaces stream synced meta of this object with the snapshot, keys which are not in the snapshot are deleted.
+    fn apply_stream_synced_meta_snapshot(self: &Rc<Self>, snapshot: &MetaSnapshot) -> VoidResult {
+        snapshot.apply(self.stream_synced_meta_keys()?, |key| {
+            self.stream_synced_meta_entry(key)
+        })
+    }
 }
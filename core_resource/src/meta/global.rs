@@ -1,5 +1,6 @@
 use crate::{
     helpers::{self, IntoString},
+    meta::typed::{MetaKey, TypedGlobalMetaEntry},
     sdk,
 };
 
@@ -23,6 +24,11 @@ pub fn entry(key: impl IntoString) -> GlobalMetaEntry {
     }
 }
 
+/// Same as [`entry`](fn.entry.html), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+pub fn typed_entry<V>(key: MetaKey<V>) -> TypedGlobalMetaEntry<GlobalMetaEntry, V> {
+    TypedGlobalMetaEntry::new(entry(key))
+}
+
 pub fn keys() -> Vec<String> {
     helpers::read_cpp_str_vec(unsafe { sdk::ICore::GetMetaDataKeys() })
 }
@@ -47,6 +53,11 @@ pub fn synced_entry(key: impl IntoString) -> GlobalSyncedMetaEntry {
     }
 }
 
+/// Same as [`synced_entry`](fn.synced_entry.html), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+pub fn typed_synced_entry<V>(key: MetaKey<V>) -> TypedGlobalMetaEntry<GlobalSyncedMetaEntry, V> {
+    TypedGlobalMetaEntry::new(synced_entry(key))
+}
+
 pub fn synced_keys() -> Vec<String> {
     helpers::read_cpp_str_vec(unsafe { sdk::ICore::GetSyncedMetaDataKeys() })
 }
@@ -1,7 +1,10 @@
 pub(crate) mod base_object;
+pub(crate) mod change;
 pub(crate) mod checkpoint_stream_synced_meta;
 pub(crate) mod entity_stream_synced_meta;
 pub(crate) mod entry;
 pub(crate) mod global;
 pub(crate) mod player_local_meta;
+pub(crate) mod snapshot;
+pub(crate) mod typed;
 pub(crate) mod ve_stream_synced_meta;
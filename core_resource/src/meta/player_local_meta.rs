@@ -2,8 +2,15 @@ use std::rc::Rc;
 
 use crate::{
     base_objects::{player::PlayerContainer, ValidBaseObject},
+    events::meta_change::MetaChangeType,
     helpers::{self, IntoString},
-    sdk, SomeResult,
+    meta::{
+        change::{self, MetaChange},
+        snapshot::MetaSnapshot,
+        typed::{MetaKey, TypedMetaEntry},
+    },
+    mvalue::FromMValue,
+    sdk, IntoVoidResult, SomeResult, VoidResult,
 };
 
 pub struct LocalPlayerMetaEntry {
@@ -35,10 +42,48 @@ where
         })
     }
 
+    /// Same as [`local_meta_entry`](#method.local_meta_entry), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+    fn typed_local_meta_entry<V>(
+        self: &Rc<Self>,
+        key: MetaKey<V>,
+    ) -> SomeResult<TypedMetaEntry<LocalPlayerMetaEntry, V>> {
+        Ok(TypedMetaEntry::new(self.local_meta_entry(key)?))
+    }
+
+    /// Subscribes to changes of specific key of local meta of this object,
+    /// handler is removed when object is destroyed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// object.on_local_meta_change("example", |change: &altv::meta::MetaChange<i64>| {
+    ///     altv::log!("changed from {:?} to {:?}", change.old_value, change.new_value);
+    /// });
+    /// ```
+    fn on_local_meta_change<V: FromMValue, R: IntoVoidResult>(
+        self: &Rc<Self>,
+        key: impl IntoString,
+        handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+    ) where
+        Self: Sized + 'static,
+    {
+        change::add_handler(MetaChangeType::Local, key, Some(self.clone()), handler);
+    }
+
     fn local_meta_keys(self: &Rc<Self>) -> SomeResult<Vec<String>> {
         let player: PlayerContainer = self.clone().into();
         Ok(helpers::read_cpp_str_vec(unsafe {
             sdk::IPlayer::GetLocalMetaDataKeys(player.raw_ptr()?)
         }))
     }
+
+    /// Copies all keys and values of local meta of this object,
+    /// see [`MetaSnapshot`](struct.MetaSnapshot.html).
+    fn local_meta_snapshot(self: &Rc<Self>) -> SomeResult<MetaSnapshot> {
+        MetaSnapshot::take(self.local_meta_keys()?, |key| self.local_meta_entry(key))
+    }
+
+    /// Replaces local meta of this object with the snapshot, keys which are not in the snapshot are deleted.
+    fn apply_local_meta_snapshot(self: &Rc<Self>, snapshot: &MetaSnapshot) -> VoidResult {
+        snapshot.apply(self.local_meta_keys()?, |key| self.local_meta_entry(key))
+    }
 }
@@ -0,0 +1,102 @@
+use std::collections::{hash_map, HashMap};
+
+use mvalue_derive::{FromMValue, IntoMValue};
+
+use crate::{mvalue::MValue, SomeResult, VoidResult};
+
+use super::base_object::entry::BaseObjectMetaEntry;
+
+/// Copy of all keys and values of one kind of meta (normal, synced, etc.) of object.
+///
+/// Can be converted to MValue (dict of keys and values) and back, so it can be saved as JSON
+/// and applied after server restart.
+///
+/// # Examples
+/// ```rust
+/// let json = altv::mvalue::MValue::from_value(vehicle.meta_snapshot()?)?.to_json();
+/// vehicle.destroy()?;
+///
+/// let snapshot = altv::meta::MetaSnapshot::try_from(&altv::mvalue::MValue::from_json(&json))?;
+/// let vehicle = altv::Vehicle::new("sultan", 0, 0)?;
+/// vehicle.apply_meta_snapshot(&snapshot)?;
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate")]
+pub struct MetaSnapshot(HashMap<String, MValue>);
+
+impl MetaSnapshot {
+    pub(crate) fn take<E: BaseObjectMetaEntry>(
+        keys: Vec<String>,
+        entry: impl Fn(String) -> SomeResult<E>,
+    ) -> SomeResult<Self> {
+        let mut values = HashMap::with_capacity(keys.len());
+        for key in keys {
+            if let Some(value) = entry(key.clone())?.get()? {
+                values.insert(key, value);
+            }
+        }
+        Ok(Self(values))
+    }
+
+    /// Replaces meta with the snapshot, keys which are not in the snapshot are deleted
+    pub(crate) fn apply<E: BaseObjectMetaEntry>(
+        &self,
+        current_keys: Vec<String>,
+        entry: impl Fn(String) -> SomeResult<E>,
+    ) -> VoidResult {
+        for key in current_keys {
+            if !self.0.contains_key(&key) {
+                entry(key)?.delete()?;
+            }
+        }
+        for (key, value) in &self.0 {
+            entry(key.clone())?.set(value)?;
+        }
+        Ok(())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<String, MValue> {
+        self.0.iter()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Returns `None` if key is not in the snapshot
+    pub fn get(&self, key: &str) -> Option<&MValue> {
+        self.0.get(key)
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: MValue) -> Option<MValue> {
+        self.0.insert(key.into(), value)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<MValue> {
+        self.0.remove(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<HashMap<String, MValue>> for MetaSnapshot {
+    fn from(values: HashMap<String, MValue>) -> Self {
+        Self(values)
+    }
+}
+
+impl From<MetaSnapshot> for HashMap<String, MValue> {
+    fn from(snapshot: MetaSnapshot) -> Self {
+        snapshot.0
+    }
+}
@@ -0,0 +1,149 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    helpers::IntoString,
+    mvalue::{FromMValue, Serializable},
+    SomeResult, VoidResult,
+};
+
+use super::{base_object::entry::BaseObjectMetaEntry, entry::MetaEntry};
+
+/// Meta key with associated type of value.
+///
+/// # Examples
+/// ```rust
+/// use altv::meta::MetaKey;
+///
+/// const MONEY: MetaKey<i64> = MetaKey::new("money");
+///
+/// let player = altv::Player::all()[0].clone();
+///
+/// player.typed_meta_entry(MONEY)?.set(100)?;
+/// let money: Option<i64> = player.typed_meta_entry(MONEY)?.get()?;
+///
+/// // global meta
+/// altv::meta::typed_entry(MONEY).set(100)?;
+/// ```
+pub struct MetaKey<T> {
+    name: &'static str,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> MetaKey<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _value: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// derive would require T to implement these traits too
+impl<T> Clone for MetaKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MetaKey<T> {}
+
+impl<T> Debug for MetaKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MetaKey<{}>({:?})",
+            std::any::type_name::<T>(),
+            self.name
+        )
+    }
+}
+
+impl<T> IntoString for MetaKey<T> {
+    fn into_string(self) -> String {
+        self.name.to_string()
+    }
+}
+
+/// Meta entry of BaseObject created from [`MetaKey`](struct.MetaKey.html)
+pub struct TypedMetaEntry<E, T> {
+    entry: E,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<E: BaseObjectMetaEntry, T> TypedMetaEntry<E, T> {
+    pub(crate) fn new(entry: E) -> Self {
+        Self {
+            entry,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn has(&self) -> SomeResult<bool> {
+        self.entry.has()
+    }
+
+    pub fn get(&self) -> SomeResult<Option<T>>
+    where
+        T: FromMValue,
+    {
+        self.entry
+            .get()?
+            .map(|value| T::from_mvalue(&value))
+            .transpose()
+    }
+
+    pub fn set(&self, value: T) -> VoidResult
+    where
+        T: TryInto<Serializable, Error = anyhow::Error>,
+    {
+        self.entry.set(value)
+    }
+
+    pub fn delete(&self) -> VoidResult {
+        self.entry.delete()
+    }
+}
+
+/// Global meta entry created from [`MetaKey`](struct.MetaKey.html)
+pub struct TypedGlobalMetaEntry<E, T> {
+    entry: E,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<E: MetaEntry, T> TypedGlobalMetaEntry<E, T> {
+    pub(crate) fn new(entry: E) -> Self {
+        Self {
+            entry,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn has(&self) -> bool {
+        self.entry.has()
+    }
+
+    pub fn get(&self) -> SomeResult<Option<T>>
+    where
+        T: FromMValue,
+    {
+        self.entry
+            .get()
+            .map(|value| T::from_mvalue(&value))
+            .transpose()
+    }
+
+    pub fn set(&self, value: T) -> VoidResult
+    where
+        T: TryInto<Serializable, Error = anyhow::Error>,
+    {
+        self.entry.set(value)
+    }
+
+    pub fn delete(&self) {
+        self.entry.delete()
+    }
+}
@@ -2,8 +2,14 @@ use std::rc::Rc;
 
 use crate::{
     base_objects::{virtual_entity::VirtualEntityContainer, ValidBaseObject},
+    events::meta_change::MetaChangeType,
     helpers::{self, IntoString},
-    sdk, SomeResult,
+    meta::{
+        change::{self, MetaChange},
+        typed::{MetaKey, TypedMetaEntry},
+    },
+    mvalue::FromMValue,
+    sdk, IntoVoidResult, SomeResult,
 };
 
 pub struct StreamSyncedVirtualEntityMetaEntry {
@@ -43,6 +49,38 @@ where
         })
     }
 
+    /// Same as [`stream_synced_meta_entry`](#method.stream_synced_meta_entry), but value type is defined by [`MetaKey`](struct.MetaKey.html)
+    fn typed_stream_synced_meta_entry<V>(
+        self: &Rc<Self>,
+        key: MetaKey<V>,
+    ) -> SomeResult<TypedMetaEntry<StreamSyncedVirtualEntityMetaEntry, V>> {
+        Ok(TypedMetaEntry::new(self.stream_synced_meta_entry(key)?))
+    }
+
+    /// Subscribes to changes of specific key of stream synced meta of this object,
+    /// handler is removed when object is destroyed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// object.on_stream_synced_meta_change("example", |change: &altv::meta::MetaChange<i64>| {
+    ///     altv::log!("changed from {:?} to {:?}", change.old_value, change.new_value);
+    /// });
+    /// ```
+    fn on_stream_synced_meta_change<V: FromMValue, R: IntoVoidResult>(
+        self: &Rc<Self>,
+        key: impl IntoString,
+        handler: impl FnMut(&MetaChange<V>) -> R + 'static,
+    ) where
+        Self: Sized + 'static,
+    {
+        change::add_handler(
+            MetaChangeType::StreamSynced,
+            key,
+            Some(self.clone()),
+            handler,
+        );
+    }
+
     fn stream_synced_meta_keys(self: &Rc<Self>) -> SomeResult<Vec<String>> {
         let entity: VirtualEntityContainer = self.clone().into();
         Ok(helpers::read_cpp_str_vec(unsafe {
@@ -1,9 +1,12 @@
 use crate::{
     base_objects::{
-        blip, checkpoint, col_shape, marker, network_object, ped, player, vehicle, virtual_entity,
-        virtual_entity_group, voice_channel, AnyBaseObject, BasePtr,
+        blip, checkpoint, col_shape, extra_pools::Entity, marker, network_object, ped, player,
+        vehicle, virtual_entity, virtual_entity_group, voice_channel, AnyBaseObject, BasePtr,
+        ValidBaseObject,
     },
+    col_shape::ColShapy,
     helpers::{read_cpp_rgba, read_cpp_vector2, read_cpp_vector3},
+    quaternion::Quaternion,
     resource::Resource,
     rgba::RGBA,
     vector::{Vector2, Vector3},
@@ -12,7 +15,18 @@ use crate::{
 use altv_sdk::{ffi as sdk, helpers::get_base_object_type};
 use anyhow::Context;
 use autocxx::{cxx::CxxVector, prelude::*};
-use std::{collections::HashMap, fmt::Debug, ptr::NonNull};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display},
+    ptr::NonNull,
+    rc::Rc,
+};
+
+mod byte_array;
+pub mod compression;
+mod json;
+
+pub use byte_array::ByteArray;
 
 pub struct Serializable(pub(crate) UniquePtr<sdk::MValueMutWrapper>);
 
@@ -55,6 +69,9 @@ impl_serializable!(u8, |value| sdk::create_mvalue_uint(value as u64));
 impl_serializable!(u16, |value| sdk::create_mvalue_uint(value as u64));
 impl_serializable!(u32, |value| sdk::create_mvalue_uint(value as u64));
 impl_serializable!(u64, sdk::create_mvalue_uint);
+
+impl_serializable!(isize, |value| sdk::create_mvalue_int(value as i64));
+impl_serializable!(usize, |value| sdk::create_mvalue_uint(value as u64));
 impl_serializable!(&[u8], |value: &[u8]| sdk::create_mvalue_byte_array(
     value.as_ptr(),
     value.len()
@@ -100,6 +117,124 @@ impl_serializable!(Vector2, |value: Vector2| sdk::create_mvalue_vector2(
     value.y(),
 ));
 
+impl_serializable!(&Vector3, |value: &Vector3| sdk::create_mvalue_vector3(
+    value.x(),
+    value.y(),
+    value.z()
+));
+
+impl_serializable!(&Vector2, |value: &Vector2| sdk::create_mvalue_vector2(
+    value.x(),
+    value.y(),
+));
+
+/// There is no quaternion mvalue type, so it's converted to dict: `{ x, y, z, w }`
+impl TryFrom<&Quaternion> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &Quaternion) -> SomeResult<Self> {
+        HashMap::from([
+            ("x", value.x()),
+            ("y", value.y()),
+            ("z", value.z()),
+            ("w", value.w()),
+        ])
+        .try_into()
+    }
+}
+
+impl TryFrom<Quaternion> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: Quaternion) -> SomeResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+fn serialize_list<T: TryInto<Serializable, Error = anyhow::Error>>(
+    iter: impl IntoIterator<Item = T>,
+) -> SomeResult<Serializable> {
+    let list = iter
+        .into_iter()
+        .map(T::try_into)
+        .collect::<SomeResult<Vec<Serializable>>>()?;
+    Serializable::try_from(list)
+}
+
+fn serialize_dict<K: Into<String>, T: TryInto<Serializable, Error = anyhow::Error>>(
+    iter: impl IntoIterator<Item = (K, T)>,
+) -> SomeResult<Serializable> {
+    let dict = iter
+        .into_iter()
+        .map(|(key, value)| Ok((key.into(), value.try_into()?)))
+        .collect::<SomeResult<HashMap<String, Serializable>>>()?;
+    Serializable::try_from(dict)
+}
+
+/// Note: `Vec<u8>` is converted to list of numbers, use `&[u8]`, `Box<[u8]>` or `mvalue::ByteArray` for `ByteArray`
+impl<T: TryInto<Serializable, Error = anyhow::Error>> TryFrom<Vec<T>> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: Vec<T>) -> SomeResult<Self> {
+        serialize_list(value)
+    }
+}
+
+impl<T: TryInto<Serializable, Error = anyhow::Error>, const N: usize> TryFrom<[T; N]>
+    for Serializable
+{
+    type Error = anyhow::Error;
+    fn try_from(value: [T; N]) -> SomeResult<Self> {
+        serialize_list(value)
+    }
+}
+
+macro_rules! impl_serializable_dict {
+    ($( $dict: ty ),+) => { $(
+        impl<T: TryInto<Serializable, Error = anyhow::Error>> TryFrom<$dict> for Serializable {
+            type Error = anyhow::Error;
+            fn try_from(value: $dict) -> SomeResult<Self> {
+                serialize_dict(value)
+            }
+        }
+    )+ };
+}
+
+impl_serializable_dict!(
+    HashMap<String, T>,
+    HashMap<&str, T>,
+    BTreeMap<String, T>,
+    BTreeMap<&str, T>
+);
+
+// tuples are converted to list, same as in JS
+macro_rules! impl_serializable_tuple {
+    ($( ( $( $name: ident ),+ ) ),+ $(,)?) => { $(
+        #[allow(non_snake_case)]
+        impl<$( $name: TryInto<Serializable, Error = anyhow::Error> ),+> TryFrom<($( $name, )+)>
+            for Serializable
+        {
+            type Error = anyhow::Error;
+            fn try_from(($( $name, )+): ($( $name, )+)) -> SomeResult<Self> {
+                let list: Vec<Serializable> = vec![$( $name.try_into()? ),+];
+                Self::try_from(list)
+            }
+        }
+    )+ };
+}
+
+impl_serializable_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L),
+);
+
 macro_rules! impl_serializable_base_object {
     ($base_object: ty, $short_name: literal) => {
         impl TryFrom<$base_object> for Serializable {
@@ -125,6 +260,12 @@ impl_serializable_base_object!(marker::MarkerContainer, "marker");
 impl_serializable_base_object!(checkpoint::CheckpointContainer, "checkpoint");
 impl_serializable_base_object!(ped::PedContainer, "ped");
 impl_serializable_base_object!(network_object::NetworkObjectContainer, "network object");
+impl_serializable_base_object!(virtual_entity::VirtualEntityContainer, "virtual entity");
+impl_serializable_base_object!(
+    virtual_entity_group::VirtualEntityGroupContainer,
+    "virtual entity group"
+);
+impl_serializable_base_object!(voice_channel::VoiceChannelContainer, "voice channel");
 
 impl<T: TryInto<Serializable, Error = anyhow::Error>> TryFrom<Option<T>> for Serializable {
     type Error = anyhow::Error;
@@ -167,7 +308,64 @@ pub fn convert_player_vec_to_cpp_vec(
     Ok(cpp_vec)
 }
 
-#[derive(Debug)]
+/// Function received from other resource, for example, as argument of local event
+#[derive(Clone)]
+pub struct MValueFunction(Rc<UniquePtr<sdk::MValueWrapper>>);
+
+impl MValueFunction {
+    /// Calls the function and returns its result
+    ///
+    /// # Examples
+    /// ```rust
+    /// altv::events::on("example", |c| {
+    ///     let callback = c.args.get_function_at(0)?;
+    ///     let result = callback.call(altv::mvalue::list![1, "text"]?);
+    ///     altv::log!("result: {result}");
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn call(&self, args: Vec<Serializable>) -> MValue {
+        let result =
+            unsafe { sdk::call_mvalue_function(self.raw(), convert_iter_to_mvalue_vec(args)) }
+                .within_unique_ptr();
+        Resource::with(|resource| deserialize_mvalue(result.as_ref().unwrap(), resource))
+    }
+
+    fn raw(&self) -> &sdk::MValueWrapper {
+        (*self.0).as_ref().unwrap()
+    }
+}
+
+/// Functions are equal if they are the same function
+impl PartialEq for MValueFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0) || unsafe { sdk::is_same_mvalue(self.raw(), other.raw()) }
+    }
+}
+
+impl Debug for MValueFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MValueFunction")
+    }
+}
+
+impl TryFrom<&MValueFunction> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &MValueFunction) -> SomeResult<Self> {
+        Ok(Self(
+            unsafe { sdk::convert_mvalue_wrapper_to_mut(value.raw()) }.within_unique_ptr(),
+        ))
+    }
+}
+
+impl TryFrom<MValueFunction> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: MValueFunction) -> SomeResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum MValue {
     Bool(bool),
     F64(f64),
@@ -179,8 +377,9 @@ pub enum MValue {
     Dict(HashMap<String, MValue>),
     Vector3(Vector3),
     Vector2(Vector2),
-    ByteArray(Vec<u8>),
+    ByteArray(ByteArray),
     RGBA(RGBA),
+    Function(MValueFunction),
 
     ColShape(col_shape::ColShapeContainer),
     Vehicle(vehicle::VehicleContainer),
@@ -220,7 +419,7 @@ macro_rules! get_mvalue_type_at {
     };
 }
 
-#[derive(Default)]
+#[derive(Default, Clone, PartialEq)]
 pub struct MValueList {
     vec: Vec<MValue>,
 }
@@ -245,8 +444,15 @@ impl MValueList {
     get_mvalue_type_at!(get_dict_at, HashMap<String, MValue>, MValue::Dict);
     get_mvalue_type_at!(get_vector3_at, Vector3, MValue::Vector3);
     get_mvalue_type_at!(get_vector2_at, Vector2, MValue::Vector2);
-    get_mvalue_type_at!(get_byte_array_at, Vec<u8>, MValue::ByteArray);
+    get_mvalue_type_at!(get_byte_array_at, ByteArray, MValue::ByteArray);
+
+    /// Same as [`get_byte_array_at`](#method.get_byte_array_at), but copies bytes to `Vec<u8>`
+    /// (`get_byte_array_at` returned `&Vec<u8>` before [`ByteArray`](struct.ByteArray.html) was added)
+    pub fn get_byte_array_vec_at(&self, index: usize) -> SomeResult<Vec<u8>> {
+        Ok(self.get_byte_array_at(index)?.to_vec())
+    }
     get_mvalue_type_at!(get_rgba_at, RGBA, MValue::RGBA);
+    get_mvalue_type_at!(get_function_at, MValueFunction, MValue::Function);
     get_mvalue_type_at!(get_player_at, player::PlayerContainer, MValue::Player);
     get_mvalue_type_at!(get_vehicle_at, vehicle::VehicleContainer, MValue::Vehicle);
     get_mvalue_type_at!(get_ped_at, ped::PedContainer, MValue::Ped);
@@ -286,6 +492,18 @@ impl MValueList {
     pub fn push(&mut self, mvalue: MValue) {
         self.vec.push(mvalue);
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MValue> {
+        self.vec.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
 }
 
 impl Debug for MValueList {
@@ -294,6 +512,304 @@ impl Debug for MValueList {
     }
 }
 
+impl MValue {
+    /// Converts any value that can be sent as MValue to `MValue`,
+    /// so it can be inspected or converted to JSON with [`to_json`](#method.to_json)
+    ///
+    /// # Examples
+    /// ```rust
+    /// let pos = altv::mvalue::MValue::from_value(player.pos()?)?;
+    /// std::fs::write("pos.json", pos.to_json().to_string())?;
+    /// ```
+    pub fn from_value(
+        value: impl TryInto<Serializable, Error = anyhow::Error>,
+    ) -> SomeResult<MValue> {
+        let serializable = value.try_into()?;
+        let wrapper =
+            unsafe { sdk::convert_mvalue_mut_wrapper_to_const(serializable.0) }.within_unique_ptr();
+        Ok(Resource::with(|resource| {
+            deserialize_mvalue(wrapper.as_ref().unwrap(), resource)
+        }))
+    }
+
+    /// Name of base object type, whether it's still valid and its id (`None` if it could not be read),
+    /// `None` if value is not a base object
+    fn base_object_info(&self) -> Option<(&'static str, bool, Option<u32>)> {
+        macro_rules! info {
+            ($name: literal, $base_object: expr) => {
+                ($name, $base_object.valid(), $base_object.id().ok())
+            };
+        }
+
+        Some(match self {
+            Self::ColShape(v) => info!("ColShape", v),
+            Self::Vehicle(v) => info!("Vehicle", v),
+            Self::Player(v) => info!("Player", v),
+            Self::Ped(v) => info!("Ped", v),
+            Self::NetworkObject(v) => info!("NetworkObject", v),
+            Self::VirtualEntity(v) => info!("VirtualEntity", v),
+            Self::VirtualEntityGroup(v) => info!("VirtualEntityGroup", v),
+            Self::Blip(v) => info!("Blip", v),
+            Self::VoiceChannel(v) => info!("VoiceChannel", v),
+            Self::Marker(v) => info!("Marker", v),
+            Self::Checkpoint(v) => info!("Checkpoint", v),
+            _ => return None,
+        })
+    }
+}
+
+/// NaN is equal to NaN, so values received from clients can be compared
+fn float_eq(a: impl Into<f64>, b: impl Into<f64>) -> bool {
+    let (a, b) = (a.into(), b.into());
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+/// Deep comparison, floats are compared with NaN being equal to NaN
+/// and base objects are equal if they are the same object.
+impl PartialEq for MValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::F64(a), Self::F64(b)) => float_eq(*a, *b),
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::None, Self::None) => true,
+            (Self::I64(a), Self::I64(b)) => a == b,
+            (Self::U64(a), Self::U64(b)) => a == b,
+            (Self::List(a), Self::List(b)) => a == b,
+            (Self::Dict(a), Self::Dict(b)) => a == b,
+            (Self::Vector3(a), Self::Vector3(b)) => {
+                float_eq(a.x(), b.x()) && float_eq(a.y(), b.y()) && float_eq(a.z(), b.z())
+            }
+            (Self::Vector2(a), Self::Vector2(b)) => {
+                float_eq(a.x(), b.x()) && float_eq(a.y(), b.y())
+            }
+            (Self::ByteArray(a), Self::ByteArray(b)) => a == b,
+            (Self::RGBA(a), Self::RGBA(b)) => {
+                (a.r(), a.g(), a.b(), a.a()) == (b.r(), b.g(), b.b(), b.a())
+            }
+            (Self::Function(a), Self::Function(b)) => a == b,
+            (Self::ColShape(a), Self::ColShape(b)) => Rc::ptr_eq(a, b),
+            (Self::Vehicle(a), Self::Vehicle(b)) => Rc::ptr_eq(a, b),
+            (Self::Player(a), Self::Player(b)) => Rc::ptr_eq(a, b),
+            (Self::Ped(a), Self::Ped(b)) => Rc::ptr_eq(a, b),
+            (Self::NetworkObject(a), Self::NetworkObject(b)) => Rc::ptr_eq(a, b),
+            (Self::VirtualEntity(a), Self::VirtualEntity(b)) => Rc::ptr_eq(a, b),
+            (Self::VirtualEntityGroup(a), Self::VirtualEntityGroup(b)) => Rc::ptr_eq(a, b),
+            (Self::Blip(a), Self::Blip(b)) => Rc::ptr_eq(a, b),
+            (Self::VoiceChannel(a), Self::VoiceChannel(b)) => Rc::ptr_eq(a, b),
+            (Self::Marker(a), Self::Marker(b)) => Rc::ptr_eq(a, b),
+            (Self::Checkpoint(a), Self::Checkpoint(b)) => Rc::ptr_eq(a, b),
+            (Self::InvalidBaseObject, Self::InvalidBaseObject) => true,
+            _ => false,
+        }
+    }
+}
+
+fn fmt_float<F: Display + Into<f64> + Copy>(
+    value: F,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    match value.into() {
+        v if v == f64::INFINITY => f.write_str("Infinity"),
+        v if v == f64::NEG_INFINITY => f.write_str("-Infinity"),
+        _ => write!(f, "{value}"),
+    }
+}
+
+fn fmt_dict_key(key: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let identifier = key
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if identifier {
+        f.write_str(key)
+    } else {
+        write!(f, "{key:?}")
+    }
+}
+
+/// Formats value the same way as `console.log` in JS (strings are quoted only when nested)
+fn fmt_nested_mvalue(value: &MValue, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some((name, valid, id)) = value.base_object_info() {
+        return match (valid, id) {
+            (false, _) => write!(f, "{name} {{ destroyed }}"),
+            (true, Some(id)) => write!(f, "{name} {{ id: {id} }}"),
+            (true, None) => write!(f, "{name} {{}}"),
+        };
+    }
+
+    match value {
+        MValue::Bool(v) => write!(f, "{v}"),
+        MValue::F64(v) => fmt_float(*v, f),
+        MValue::String(v) => write!(f, "{v:?}"),
+        MValue::None => f.write_str("null"),
+        MValue::I64(v) => write!(f, "{v}"),
+        MValue::U64(v) => write!(f, "{v}"),
+        MValue::List(v) => write!(f, "{v}"),
+        MValue::Dict(dict) => {
+            if dict.is_empty() {
+                return f.write_str("{}");
+            }
+            // HashMap order is random, sort it to keep output stable
+            let mut entries: Vec<_> = dict.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            f.write_str("{ ")?;
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                fmt_dict_key(key, f)?;
+                f.write_str(": ")?;
+                fmt_nested_mvalue(value, f)?;
+            }
+            f.write_str(" }")
+        }
+        MValue::Vector3(v) => {
+            f.write_str("Vector3 { x: ")?;
+            fmt_float(v.x(), f)?;
+            f.write_str(", y: ")?;
+            fmt_float(v.y(), f)?;
+            f.write_str(", z: ")?;
+            fmt_float(v.z(), f)?;
+            f.write_str(" }")
+        }
+        MValue::Vector2(v) => {
+            f.write_str("Vector2 { x: ")?;
+            fmt_float(v.x(), f)?;
+            f.write_str(", y: ")?;
+            fmt_float(v.y(), f)?;
+            f.write_str(" }")
+        }
+        MValue::ByteArray(v) => {
+            write!(f, "Uint8Array({})", v.len())?;
+            if v.is_empty() {
+                return f.write_str(" []");
+            }
+            f.write_str(" [ ")?;
+            for (index, byte) in v.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{byte}")?;
+            }
+            f.write_str(" ]")
+        }
+        MValue::RGBA(v) => write!(
+            f,
+            "RGBA {{ r: {}, g: {}, b: {}, a: {} }}",
+            v.r(),
+            v.g(),
+            v.b(),
+            v.a()
+        ),
+        MValue::Function(_) => f.write_str("[Function]"),
+        MValue::InvalidBaseObject => f.write_str("InvalidBaseObject"),
+        // all other base objects are handled above
+        _ => unreachable!(),
+    }
+}
+
+/// # Examples
+/// ```rust
+/// altv::events::on_client("example", |c| {
+///     // prints something like `[1, "text", { key: true }, Vector3 { x: 1, y: 2, z: 3 }]`
+///     altv::log!("{}", c.args);
+///     Ok(())
+/// });
+/// ```
+impl Display for MValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(value) => f.write_str(value),
+            value => fmt_nested_mvalue(value, f),
+        }
+    }
+}
+
+impl Display for MValueList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[")?;
+        for (index, value) in self.vec.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            fmt_nested_mvalue(value, f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Allows to send received value back, for example, to forward event args to other players
+///
+/// # Examples
+/// ```rust
+/// altv::events::on_client("forward_me", |c| {
+///     // sends the first argument unchanged to all players
+///     altv::events::emit_all_clients!("forwarded", c.args.get(0)?)
+/// });
+/// ```
+impl TryFrom<&MValue> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::Bool(v) => Self::try_from(*v),
+            MValue::F64(v) => Self::try_from(*v),
+            MValue::String(v) => Self::try_from(v.as_str()),
+            MValue::None => Self::try_from(()),
+            MValue::I64(v) => Self::try_from(*v),
+            MValue::U64(v) => Self::try_from(*v),
+            MValue::List(v) => Self::try_from(v),
+            MValue::Dict(v) => v
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), Self::try_from(value)?)))
+                .collect::<SomeResult<HashMap<_, _>>>()?
+                .try_into(),
+            MValue::Vector3(v) => Self::try_from(*v),
+            MValue::Vector2(v) => Self::try_from(*v),
+            MValue::ByteArray(v) => Self::try_from(v.as_slice()),
+            MValue::RGBA(v) => Self::try_from(v),
+            MValue::Function(v) => Self::try_from(v),
+            MValue::ColShape(v) => Self::try_from(v.clone()),
+            MValue::Vehicle(v) => Self::try_from(v.clone()),
+            MValue::Player(v) => Self::try_from(v.clone()),
+            MValue::Ped(v) => Self::try_from(v.clone()),
+            MValue::NetworkObject(v) => Self::try_from(v.clone()),
+            MValue::VirtualEntity(v) => Self::try_from(v.clone()),
+            MValue::VirtualEntityGroup(v) => Self::try_from(v.clone()),
+            MValue::Blip(v) => Self::try_from(v.clone()),
+            MValue::VoiceChannel(v) => Self::try_from(v.clone()),
+            MValue::Marker(v) => Self::try_from(v.clone()),
+            MValue::Checkpoint(v) => Self::try_from(v.clone()),
+            MValue::InvalidBaseObject => {
+                anyhow::bail!("Failed to convert invalid base object to mvalue")
+            }
+        }
+    }
+}
+
+impl TryFrom<MValue> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: MValue) -> SomeResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<&MValueList> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &MValueList) -> SomeResult<Self> {
+        value
+            .iter()
+            .map(Self::try_from)
+            .collect::<SomeResult<Vec<_>>>()?
+            .try_into()
+    }
+}
+
 pub fn deserialize_mvalue_args(
     args: UniquePtr<CxxVector<sdk::MValueWrapper>>,
     resource: &Resource,
@@ -376,23 +892,13 @@ pub(crate) fn deserialize_mvalue(cpp_wrapper: &sdk::MValueWrapper, resource: &Re
         Vector2 => MValue::Vector2(read_cpp_vector2(
             unsafe { sdk::get_mvalue_vector2(cpp_wrapper) }.within_unique_ptr(),
         )),
-        ByteArray => {
-            let size = unsafe { sdk::get_mvalue_byte_array_size(cpp_wrapper) };
-            logger::debug!("byte array size: {size}");
-            let mut buffer = Vec::<u8>::with_capacity(size);
-            unsafe {
-                sdk::get_mvalue_byte_array(cpp_wrapper, buffer.as_mut_ptr());
-                buffer.set_len(size);
-            }
-            MValue::ByteArray(buffer)
-        }
+        ByteArray => MValue::ByteArray(self::ByteArray::from_cpp(cpp_wrapper)),
         Rgba => MValue::RGBA(read_cpp_rgba(
             unsafe { sdk::get_mvalue_rgba(cpp_wrapper) }.within_unique_ptr(),
         )),
-        _ => {
-            logger::error!("[deserialize_mvalue] unknown mvalue type: {mvalue_type:?}");
-            MValue::None
-        }
+        Function => MValue::Function(MValueFunction(Rc::new(
+            unsafe { cpp_wrapper.clone() }.within_unique_ptr(),
+        ))),
     }
 }
 
@@ -424,3 +930,216 @@ pub(crate) fn deserialize_from_sdk(
 ) -> MValue {
     deserialize_mvalue(value.within_unique_ptr().as_ref().unwrap(), resource)
 }
+
+/// Converts deserialized [`MValue`](enum.MValue.html) back to Rust type,
+/// for example, when reading value of [typed meta key](../meta/struct.MetaKey.html)
+pub trait FromMValue: Sized {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self>;
+}
+
+macro_rules! impl_from_mvalue {
+    ($type: ty, $( $mvalue_type: path )|+) => {
+        impl FromMValue for $type {
+            fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+                match value {
+                    $( $mvalue_type(value) => Ok(value.clone()), )+
+                    value => anyhow::bail!(
+                        "Expected mvalue of type: {}, received: {value:?}",
+                        stringify!($type)
+                    ),
+                }
+            }
+        }
+    };
+}
+
+impl_from_mvalue!(String, MValue::String);
+
+impl_from_mvalue!(col_shape::ColShapeContainer, MValue::ColShape);
+impl_from_mvalue!(vehicle::VehicleContainer, MValue::Vehicle);
+impl_from_mvalue!(player::PlayerContainer, MValue::Player);
+impl_from_mvalue!(ped::PedContainer, MValue::Ped);
+impl_from_mvalue!(
+    network_object::NetworkObjectContainer,
+    MValue::NetworkObject
+);
+impl_from_mvalue!(
+    virtual_entity::VirtualEntityContainer,
+    MValue::VirtualEntity
+);
+impl_from_mvalue!(
+    virtual_entity_group::VirtualEntityGroupContainer,
+    MValue::VirtualEntityGroup
+);
+impl_from_mvalue!(blip::BlipContainer, MValue::Blip);
+impl_from_mvalue!(voice_channel::VoiceChannelContainer, MValue::VoiceChannel);
+impl_from_mvalue!(marker::MarkerContainer, MValue::Marker);
+impl_from_mvalue!(checkpoint::CheckpointContainer, MValue::Checkpoint);
+
+impl_from_mvalue!(MValueFunction, MValue::Function);
+impl_from_mvalue!(ByteArray, MValue::ByteArray);
+
+/// Value is kept as it is, useful for fields of derived types which can hold anything
+impl FromMValue for MValue {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl FromMValue for bool {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::Bool(value) => Ok(*value),
+            value => anyhow::bail!("Expected mvalue of type: bool, received: {value:?}"),
+        }
+    }
+}
+
+impl FromMValue for Vector3 {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::Vector3(value) => Ok(Vector3::new(value.x(), value.y(), value.z())),
+            value => anyhow::bail!("Expected mvalue of type: Vector3, received: {value:?}"),
+        }
+    }
+}
+
+impl FromMValue for Vector2 {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::Vector2(value) => Ok(Vector2::new(value.x(), value.y())),
+            value => anyhow::bail!("Expected mvalue of type: Vector2, received: {value:?}"),
+        }
+    }
+}
+
+impl FromMValue for Quaternion {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        let MValue::Dict(dict) = value else {
+            anyhow::bail!("Expected mvalue of type: Quaternion (dict), received: {value:?}");
+        };
+        let component = |key: &str| {
+            let value = dict
+                .get(key)
+                .with_context(|| format!("Quaternion dict has no key: {key:?}"))?;
+            f32::from_mvalue(value)
+        };
+        Ok(Quaternion::new(
+            component("x")?,
+            component("y")?,
+            component("z")?,
+            component("w")?,
+        ))
+    }
+}
+
+impl FromMValue for RGBA {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::RGBA(value) => Ok(RGBA::new(value.r(), value.g(), value.b(), value.a())),
+            value => anyhow::bail!("Expected mvalue of type: RGBA, received: {value:?}"),
+        }
+    }
+}
+
+// JS numbers can be received as any of these types, so integers are converted with range check
+macro_rules! impl_from_mvalue_number {
+    ($( $type: ty ),+) => {
+        $(
+            impl FromMValue for $type {
+                fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+                    let converted = match value {
+                        MValue::I64(value) => <$type>::try_from(*value).ok(),
+                        MValue::U64(value) => <$type>::try_from(*value).ok(),
+                        &MValue::F64(value) if value.fract() == 0.0 => {
+                            if value >= <$type>::MIN as f64 && value <= <$type>::MAX as f64 {
+                                Some(value as $type)
+                            } else {
+                                None
+                            }
+                        }
+                        value => anyhow::bail!(
+                            "Expected mvalue of type: {}, received: {value:?}",
+                            stringify!($type)
+                        ),
+                    };
+
+                    converted.ok_or_else(|| anyhow::anyhow!(
+                        "Number mvalue is out of range of type: {}",
+                        stringify!($type)
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_mvalue_number!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl FromMValue for f64 {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        Ok(match value {
+            MValue::F64(value) => *value,
+            MValue::I64(value) => *value as f64,
+            MValue::U64(value) => *value as f64,
+            value => anyhow::bail!("Expected mvalue of type: f64, received: {value:?}"),
+        })
+    }
+}
+
+impl FromMValue for f32 {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        f64::from_mvalue(value).map(|value| value as f32)
+    }
+}
+
+impl<T: FromMValue> FromMValue for Option<T> {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::None => Ok(None),
+            value => T::from_mvalue(value).map(Some),
+        }
+    }
+}
+
+/// `Vec<u8>` (or any other number type) can also be converted from `ByteArray`
+impl<T: FromMValue> FromMValue for Vec<T> {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        match value {
+            MValue::List(list) => list.vec.iter().map(T::from_mvalue).collect(),
+            MValue::ByteArray(bytes) => bytes
+                .iter()
+                .map(|byte| T::from_mvalue(&MValue::U64(*byte as u64)))
+                .collect(),
+            value => anyhow::bail!("Expected mvalue of type: Vec, received: {value:?}"),
+        }
+    }
+}
+
+fn dict_from_mvalue<T: FromMValue, C: FromIterator<(String, T)>>(
+    value: &MValue,
+    type_name: &str,
+) -> SomeResult<C> {
+    let MValue::Dict(dict) = value else {
+        anyhow::bail!("Expected mvalue of type: {type_name}, received: {value:?}");
+    };
+    dict.iter()
+        .map(|(key, value)| {
+            let value = T::from_mvalue(value)
+                .with_context(|| format!("Failed to convert value of key: {key:?}"))?;
+            Ok((key.clone(), value))
+        })
+        .collect()
+}
+
+impl<T: FromMValue> FromMValue for HashMap<String, T> {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        dict_from_mvalue(value, "HashMap")
+    }
+}
+
+impl<T: FromMValue> FromMValue for BTreeMap<String, T> {
+    fn from_mvalue(value: &MValue) -> SomeResult<Self> {
+        dict_from_mvalue(value, "BTreeMap")
+    }
+}
@@ -0,0 +1,176 @@
+use std::{fmt::Debug, ops::Deref, rc::Rc};
+
+use autocxx::prelude::*;
+
+use crate::{sdk, SomeResult};
+
+use super::Serializable;
+
+/// Bytes of `ByteArray` mvalue (`Uint8Array` in JS).
+///
+/// Received byte arrays are not copied, bytes are read directly from alt:V value
+/// (which is kept alive as long as `ByteArray` exists), so [`as_slice`](#method.as_slice)
+/// of an event argument is a view tied to the lifetime of the event context.
+///
+/// Sending is not zero-copy: alt:V creates the value from a pointer and length,
+/// so bytes of `&[u8]`, `Box<[u8]>`, `ByteArray` and `bytes::Bytes` are copied once into it.
+///
+/// `MValue::ByteArray` used to contain `Vec<u8>`, code written for it can be migrated
+/// by using `ByteArray` as `&[u8]` (it implements `Deref<Target = [u8]>`), converting it with
+/// [`into_vec`](#method.into_vec) or `Vec::from`, or reading arguments with
+/// [`get_byte_array_vec_at`](struct.MValueList.html#method.get_byte_array_vec_at).
+///
+/// # Examples
+/// ```rust
+/// altv::events::on_client("voice_data", |c| {
+///     let bytes: &[u8] = c.args.get_byte_array_at(0)?;
+///     altv::log!("received {} bytes", bytes.len());
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ByteArray(Inner);
+
+#[derive(Clone)]
+enum Inner {
+    Owned(Vec<u8>),
+    Received {
+        // keeps alt:V value alive, `data` points to its bytes
+        _mvalue: Rc<UniquePtr<sdk::MValueWrapper>>,
+        data: *const u8,
+        len: usize,
+    },
+}
+
+impl ByteArray {
+    pub(crate) fn from_cpp(cpp_wrapper: &sdk::MValueWrapper) -> Self {
+        let mvalue = Rc::new(unsafe { cpp_wrapper.clone() }.within_unique_ptr());
+        let wrapper = (*mvalue).as_ref().unwrap();
+        let len = unsafe { sdk::get_mvalue_byte_array_size(wrapper) };
+        let data = unsafe { sdk::get_mvalue_byte_array_data(wrapper) };
+        logger::debug!("byte array size: {len}");
+
+        Self(Inner::Received {
+            _mvalue: mvalue,
+            data,
+            len,
+        })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        match &self.0 {
+            Inner::Owned(vec) => vec,
+            Inner::Received { len: 0, .. } => &[],
+            Inner::Received { data, len, .. } => unsafe { std::slice::from_raw_parts(*data, *len) },
+        }
+    }
+
+    /// Converts bytes to `Vec<u8>`, copies them only if they were received from alt:V
+    pub fn into_vec(self) -> Vec<u8> {
+        match self.0 {
+            Inner::Owned(vec) => vec,
+            Inner::Received { .. } => self.as_slice().to_vec(),
+        }
+    }
+}
+
+impl Deref for ByteArray {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for ByteArray {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Debug for ByteArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl PartialEq for ByteArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for ByteArray {}
+
+impl PartialEq<[u8]> for ByteArray {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl PartialEq<Vec<u8>> for ByteArray {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl From<Vec<u8>> for ByteArray {
+    fn from(value: Vec<u8>) -> Self {
+        Self(Inner::Owned(value))
+    }
+}
+
+impl From<Box<[u8]>> for ByteArray {
+    fn from(value: Box<[u8]>) -> Self {
+        Self(Inner::Owned(value.into_vec()))
+    }
+}
+
+impl From<&[u8]> for ByteArray {
+    fn from(value: &[u8]) -> Self {
+        Self(Inner::Owned(value.to_vec()))
+    }
+}
+
+impl From<ByteArray> for Vec<u8> {
+    fn from(value: ByteArray) -> Self {
+        value.into_vec()
+    }
+}
+
+impl TryFrom<&ByteArray> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &ByteArray) -> SomeResult<Self> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl TryFrom<ByteArray> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: ByteArray) -> SomeResult<Self> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl TryFrom<Box<[u8]>> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: Box<[u8]>) -> SomeResult<Self> {
+        Self::try_from(&*value)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl TryFrom<bytes::Bytes> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: bytes::Bytes) -> SomeResult<Self> {
+        Self::try_from(&*value)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl TryFrom<&bytes::Bytes> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &bytes::Bytes) -> SomeResult<Self> {
+        Self::try_from(&**value)
+    }
+}
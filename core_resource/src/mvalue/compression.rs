@@ -0,0 +1,75 @@
+//! Helpers for compressing large payloads (map data, state blobs, etc.) before sending them
+//! to clients as `ByteArray`, each algorithm is behind a feature flag of the same name.
+//!
+//! Compressed bytes are returned as `Box<[u8]>` so they can be passed directly
+//! to events or meta setters without conversion to list of numbers.
+//!
+//! Decompression requires `max_size` (in bytes) of decompressed data, because payloads received
+//! from clients can't be trusted, small compressed payload can expand to gigabytes.
+
+#[cfg(feature = "zstd")]
+pub mod zstd {
+    use std::io::Read;
+
+    use crate::SomeResult;
+
+    /// Compresses bytes to zstd frame, `level` is from 1 to 22 (0 means default level)
+    ///
+    /// # Examples
+    /// ```rust
+    /// let compressed = altv::mvalue::compression::zstd::compress(&map_data, 3)?;
+    /// altv::events::emit_client!("map_data", player, compressed)?;
+    /// ```
+    pub fn compress(bytes: &[u8], level: i32) -> SomeResult<Box<[u8]>> {
+        Ok(::zstd::bulk::compress(bytes, level)?.into_boxed_slice())
+    }
+
+    /// Decompresses zstd frame, returns error if decompressed data is larger than `max_size`
+    ///
+    /// # Examples
+    /// ```rust
+    /// altv::events::on_client("map_data", |c| {
+    ///     let bytes = c.args.get_byte_array_at(0)?;
+    ///     // at most 1 MiB
+    ///     let map_data = altv::mvalue::compression::zstd::decompress(&bytes, 1024 * 1024)?;
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn decompress(bytes: &[u8], max_size: usize) -> SomeResult<Vec<u8>> {
+        let mut decompressed = vec![];
+        // one byte more than allowed is read to detect that data is too large
+        ::zstd::stream::Decoder::new(bytes)?
+            .take(max_size as u64 + 1)
+            .read_to_end(&mut decompressed)?;
+        if decompressed.len() > max_size {
+            anyhow::bail!("Decompressed data is larger than max size: {max_size}");
+        }
+        Ok(decompressed)
+    }
+}
+
+#[cfg(feature = "lz4")]
+pub mod lz4 {
+    use crate::SomeResult;
+
+    /// Compresses bytes to lz4 block with uncompressed size prepended (little endian u32)
+    ///
+    /// # Examples
+    /// ```rust
+    /// let compressed = altv::mvalue::compression::lz4::compress(&state);
+    /// altv::events::emit_client!("state", player, compressed)?;
+    /// ```
+    pub fn compress(bytes: &[u8]) -> Box<[u8]> {
+        lz4_flex::compress_prepend_size(bytes).into_boxed_slice()
+    }
+
+    /// Decompresses lz4 block with prepended size, returns error if the size is larger than `max_size`
+    /// (checked before anything is allocated)
+    pub fn decompress(bytes: &[u8], max_size: usize) -> SomeResult<Vec<u8>> {
+        let (size, block) = lz4_flex::block::uncompressed_size(bytes)?;
+        if size > max_size {
+            anyhow::bail!("Decompressed data size: {size} is larger than max size: {max_size}");
+        }
+        Ok(lz4_flex::decompress(block, size)?)
+    }
+}
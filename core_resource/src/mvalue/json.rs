@@ -0,0 +1,256 @@
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    base_objects::{network_object, ped, player, vehicle},
+    col_shape::ColShapy,
+    resource::Resource,
+    rgba::RGBA,
+    vector::{Vector2, Vector3},
+    SomeResult,
+};
+
+use super::{MValue, MValueList, Serializable};
+
+/// Key of JSON object which tells what type of MValue is encoded in it
+const TYPE_KEY: &str = "type";
+
+impl MValue {
+    /// Converts value to JSON.
+    ///
+    /// Types which don't exist in JSON are encoded as objects with `"type"` key:
+    /// - `Vector3`: `{ "type": "Vector3", "x": 1.0, "y": 2.0, "z": 3.0 }`
+    /// - `Vector2`: `{ "type": "Vector2", "x": 1.0, "y": 2.0 }`
+    /// - `RGBA`: `{ "type": "RGBA", "r": 255, "g": 0, "b": 0, "a": 255 }`
+    /// - `ByteArray`: `{ "type": "ByteArray", "data": "0aff" }` (hex string)
+    /// - base objects: `{ "type": "Player", "id": 1 }`, `id` is `null` if object is destroyed
+    ///
+    /// `NaN`, infinite numbers and functions are converted to `null`, same as `JSON.stringify` does in JS.
+    ///
+    /// # Examples
+    /// ```rust
+    /// altv::events::on_client("example", |c| {
+    ///     // [1,"text",{"type":"Vector3","x":1.0,"y":2.0,"z":3.0}]
+    ///     altv::log!("{}", c.args.to_json());
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn to_json(&self) -> Value {
+        if let Some((name, valid, id)) = self.base_object_info() {
+            let id = if valid { id } else { None };
+            return object([(TYPE_KEY, name.into()), ("id", id.into())]);
+        }
+
+        match self {
+            Self::Bool(v) => Value::Bool(*v),
+            Self::F64(v) => float(*v),
+            Self::String(v) => Value::String(v.clone()),
+            // functions can't be represented in JSON, same as in JS
+            Self::None | Self::InvalidBaseObject | Self::Function(_) => Value::Null,
+            Self::I64(v) => (*v).into(),
+            Self::U64(v) => (*v).into(),
+            Self::List(v) => v.to_json(),
+            Self::Dict(v) => Value::Object(
+                v.iter()
+                    .map(|(key, value)| (key.clone(), value.to_json()))
+                    .collect(),
+            ),
+            Self::Vector3(v) => object([
+                (TYPE_KEY, "Vector3".into()),
+                ("x", float(v.x())),
+                ("y", float(v.y())),
+                ("z", float(v.z())),
+            ]),
+            Self::Vector2(v) => object([
+                (TYPE_KEY, "Vector2".into()),
+                ("x", float(v.x())),
+                ("y", float(v.y())),
+            ]),
+            Self::ByteArray(v) => object([
+                (TYPE_KEY, "ByteArray".into()),
+                (
+                    "data",
+                    v.iter()
+                        .map(|byte| format!("{byte:02x}"))
+                        .collect::<String>()
+                        .into(),
+                ),
+            ]),
+            Self::RGBA(v) => object([
+                (TYPE_KEY, "RGBA".into()),
+                ("r", v.r().into()),
+                ("g", v.g().into()),
+                ("b", v.b().into()),
+                ("a", v.a().into()),
+            ]),
+            // all other base objects are handled above
+            _ => unreachable!(),
+        }
+    }
+
+    /// Converts JSON back to MValue using the encoding described in [`to_json`](#method.to_json).
+    ///
+    /// Objects are decoded as special types only if they have exactly the same keys as in the encoding,
+    /// otherwise they are decoded as `Dict`.
+    /// Base objects which no longer exist are decoded as `InvalidBaseObject`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// let json = altv::serde_json::json!({ "pos": { "type": "Vector3", "x": 1, "y": 2, "z": 3 } });
+    /// let value = altv::mvalue::MValue::from_json(&json);
+    /// altv::meta::entry("spawn").set(&value)?;
+    /// ```
+    pub fn from_json(value: &Value) -> MValue {
+        match value {
+            Value::Null => Self::None,
+            Value::Bool(v) => Self::Bool(*v),
+            Value::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    Self::I64(v)
+                } else if let Some(v) = v.as_u64() {
+                    Self::U64(v)
+                } else {
+                    Self::F64(v.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::String(v) => Self::String(v.clone()),
+            Value::Array(v) => Self::List(MValueList::new(v.iter().map(Self::from_json).collect())),
+            Value::Object(v) => decode_special(v).unwrap_or_else(|| {
+                Self::Dict(
+                    v.iter()
+                        .map(|(key, value)| (key.clone(), Self::from_json(value)))
+                        .collect(),
+                )
+            }),
+        }
+    }
+}
+
+impl MValueList {
+    /// Converts list to JSON array, see [`MValue::to_json`](enum.MValue.html#method.to_json)
+    pub fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(MValue::to_json).collect())
+    }
+}
+
+impl TryFrom<&Value> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: &Value) -> SomeResult<Self> {
+        Self::try_from(&MValue::from_json(value))
+    }
+}
+
+impl TryFrom<Value> for Serializable {
+    type Error = anyhow::Error;
+    fn try_from(value: Value) -> SomeResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+fn object<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn float(value: impl Into<f64>) -> Value {
+    Number::from_f64(value.into()).map_or(Value::Null, Value::Number)
+}
+
+fn decode_special(object: &Map<String, Value>) -> Option<MValue> {
+    let type_name = object.get(TYPE_KEY)?.as_str()?;
+
+    let has_only = |keys: &[&str]| {
+        object.len() == keys.len() + 1 && keys.iter().all(|key| object.contains_key(*key))
+    };
+    let f32_field = |key: &str| object.get(key)?.as_f64().map(|v| v as f32);
+    let u8_field = |key: &str| u8::try_from(object.get(key)?.as_u64()?).ok();
+
+    Some(match type_name {
+        "Vector3" if has_only(&["x", "y", "z"]) => MValue::Vector3(Vector3::new(
+            f32_field("x")?,
+            f32_field("y")?,
+            f32_field("z")?,
+        )),
+        "Vector2" if has_only(&["x", "y"]) => {
+            MValue::Vector2(Vector2::new(f32_field("x")?, f32_field("y")?))
+        }
+        "RGBA" if has_only(&["r", "g", "b", "a"]) => MValue::RGBA(RGBA::new(
+            u8_field("r")?,
+            u8_field("g")?,
+            u8_field("b")?,
+            u8_field("a")?,
+        )),
+        "ByteArray" if has_only(&["data"]) => {
+            MValue::ByteArray(decode_hex(object.get("data")?.as_str()?)?.into())
+        }
+        name if has_only(&["id"]) => decode_base_object(name, object.get("id")?)?,
+        _ => return None,
+    })
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.is_ascii() || hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Returns `None` if `name` is not a name of base object type
+fn decode_base_object(name: &str, id: &Value) -> Option<MValue> {
+    macro_rules! find_in_pool {
+        ($pool: ident, $id: expr) => {
+            Resource::with_base_objects_ref(|v, _| v.$pool.all())
+                .into_iter()
+                .find(|base_object| base_object.id().ok() == Some($id))
+        };
+    }
+
+    let id = match id {
+        Value::Null => None,
+        id => Some(u32::try_from(id.as_u64()?).ok()?),
+    };
+    let Some(id) = id else {
+        return matches!(
+            name,
+            "ColShape"
+                | "Vehicle"
+                | "Player"
+                | "Ped"
+                | "NetworkObject"
+                | "VirtualEntity"
+                | "VirtualEntityGroup"
+                | "Blip"
+                | "VoiceChannel"
+                | "Marker"
+                | "Checkpoint"
+        )
+        .then_some(MValue::InvalidBaseObject);
+    };
+
+    let value = match name {
+        "Vehicle" => vehicle::Vehicle::get_by_id(id).ok().map(MValue::Vehicle),
+        "Player" => player::Player::get_by_id(id).ok().map(MValue::Player),
+        "Ped" => ped::Ped::get_by_id(id).ok().map(MValue::Ped),
+        "NetworkObject" => network_object::NetworkObject::get_by_id(id)
+            .ok()
+            .map(MValue::NetworkObject),
+        "VirtualEntity" => find_in_pool!(virtual_entity, id).map(MValue::VirtualEntity),
+        "VirtualEntityGroup" => {
+            find_in_pool!(virtual_entity_group, id).map(MValue::VirtualEntityGroup)
+        }
+        "Blip" => find_in_pool!(blip, id).map(MValue::Blip),
+        "VoiceChannel" => find_in_pool!(voice_channel, id).map(MValue::VoiceChannel),
+        "Marker" => find_in_pool!(marker, id).map(MValue::Marker),
+        "ColShape" => find_in_pool!(col_shape, id).map(MValue::ColShape),
+        "Checkpoint" => find_in_pool!(checkpoint, id).map(MValue::Checkpoint),
+        _ => return None,
+    };
+
+    Some(value.unwrap_or(MValue::InvalidBaseObject))
+}
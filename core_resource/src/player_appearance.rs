@@ -0,0 +1,158 @@
+use mvalue_derive::{FromMValue, IntoMValue};
+
+use crate::{
+    base_objects::{extra_pools::Entity, player},
+    helpers::Hash,
+    rgba::RGBA,
+    structs::{PlayerDlcCloth, PlayerDlcProp, PlayerHeadBlendData, PlayerHeadOverlay},
+    SomeResult, VoidResult,
+};
+
+/// Full look of player character: model, head blend, face features, head overlays, clothes, props and colors.
+///
+/// Can be converted to MValue and back, so it can be sent to client, stored in meta or saved as JSON.
+///
+/// # Examples
+/// ```rust
+/// // save
+/// let json = altv::mvalue::MValue::from_value(player.appearance()?)?.to_json();
+///
+/// // load
+/// let appearance = altv::PlayerAppearance::try_from(&altv::mvalue::MValue::from_json(&json))?;
+/// player.apply_appearance(&appearance)?;
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct PlayerAppearance {
+    pub model: Hash,
+    pub head_blend: PlayerHeadBlendData,
+    /// Scales of face features, index is feature id
+    pub face_features: Vec<f32>,
+    /// Index is overlay id, `None` if overlay is not set
+    pub head_overlays: Vec<Option<PlayerHeadOverlay>>,
+    pub head_blend_palette_colors: Vec<RGBA>,
+    pub eye_color: i16,
+    pub hair_color: u8,
+    pub hair_highlight_color: u8,
+    /// Index is component id
+    pub clothes: Vec<PlayerDlcCloth>,
+    /// Same order as in [`PROP_COMPONENTS`](#associatedconstant.PROP_COMPONENTS), `None` if there is no prop
+    pub props: Vec<Option<PlayerDlcProp>>,
+}
+
+impl PlayerAppearance {
+    pub const FACE_FEATURES: u8 = 20;
+    pub const HEAD_OVERLAYS: u8 = 13;
+    pub const HEAD_BLEND_PALETTE_COLORS: u8 = 4;
+    pub const CLOTH_COMPONENTS: u8 = 12;
+    /// Hats, glasses, ears, watches and bracelets
+    pub const PROP_COMPONENTS: [u8; 5] = [0, 1, 2, 6, 7];
+}
+
+/// Index of head overlay and drawable of prop which means "nothing"
+const NONE_INDEX: u8 = 255;
+
+/// # **`Player appearance implementation`**
+impl player::Player {
+    /// Reads everything that is saved in [`PlayerAppearance`](struct.PlayerAppearance.html)
+    pub fn appearance(&self) -> SomeResult<PlayerAppearance> {
+        let head_overlays = (0..PlayerAppearance::HEAD_OVERLAYS)
+            .map(|id| {
+                let overlay = self.get_head_overlay(id)?;
+                Ok((overlay.index != NONE_INDEX).then_some(overlay))
+            })
+            .collect::<SomeResult<_>>()?;
+
+        let props = PlayerAppearance::PROP_COMPONENTS
+            .into_iter()
+            .map(|component| {
+                let prop = self.get_dlc_prop(component)?;
+                Ok((prop.drawable != NONE_INDEX).then_some(prop))
+            })
+            .collect::<SomeResult<_>>()?;
+
+        Ok(PlayerAppearance {
+            model: self.model()?,
+            head_blend: self.get_head_blend_data()?,
+            face_features: (0..PlayerAppearance::FACE_FEATURES)
+                .map(|index| self.get_face_feature_scale(index))
+                .collect::<SomeResult<_>>()?,
+            head_overlays,
+            head_blend_palette_colors: (0..PlayerAppearance::HEAD_BLEND_PALETTE_COLORS)
+                .map(|id| self.get_head_blend_palette_color(id))
+                .collect::<SomeResult<_>>()?,
+            eye_color: self.get_eye_color()?,
+            hair_color: self.get_hair_color()?,
+            hair_highlight_color: self.get_hair_highlight_color()?,
+            clothes: (0..PlayerAppearance::CLOTH_COMPONENTS)
+                .map(|component| self.get_dlc_clothes(component))
+                .collect::<SomeResult<_>>()?,
+            props,
+        })
+    }
+
+    /// Applies everything from [`PlayerAppearance`](struct.PlayerAppearance.html),
+    /// model is only changed if it's different (changing model resets appearance).
+    ///
+    /// Lists may be shorter than in captured appearance, missing entries are left unchanged
+    /// and extra entries are ignored.
+    pub fn apply_appearance(&self, appearance: &PlayerAppearance) -> VoidResult {
+        if self.model()? != appearance.model {
+            self.set_model(appearance.model)?;
+        }
+
+        self.set_head_blend_data(appearance.head_blend.clone())?;
+
+        for (index, scale) in (0..PlayerAppearance::FACE_FEATURES).zip(&appearance.face_features) {
+            self.set_face_feature(index, *scale)?;
+        }
+
+        for (id, overlay) in (0..PlayerAppearance::HEAD_OVERLAYS).zip(&appearance.head_overlays) {
+            let Some(overlay) = overlay else {
+                self.remove_head_overlay(id)?;
+                continue;
+            };
+            self.set_head_overlay(id, overlay.index, overlay.opacity)?;
+            self.set_head_overlay_color(
+                id,
+                overlay.color_type,
+                overlay.color_index,
+                overlay.second_color_index,
+            )?;
+        }
+
+        for (id, color) in (0..PlayerAppearance::HEAD_BLEND_PALETTE_COLORS)
+            .zip(&appearance.head_blend_palette_colors)
+        {
+            self.set_head_blend_palette_color(id, color.r(), color.g(), color.b())?;
+        }
+
+        self.set_eye_color(appearance.eye_color)?;
+        self.set_hair_color(appearance.hair_color)?;
+        self.set_hair_highlight_color(appearance.hair_highlight_color)?;
+
+        for (component, cloth) in (0..PlayerAppearance::CLOTH_COMPONENTS).zip(&appearance.clothes) {
+            self.set_dlc_clothes(
+                component,
+                cloth.drawable,
+                cloth.texture,
+                cloth.palette,
+                cloth.dlc,
+            )?;
+        }
+
+        for (component, prop) in PlayerAppearance::PROP_COMPONENTS
+            .into_iter()
+            .zip(&appearance.props)
+        {
+            match prop {
+                Some(prop) => {
+                    self.set_dlc_prop(component, prop.drawable, prop.texture, prop.dlc)?;
+                }
+                None => self.clear_props(component)?,
+            }
+        }
+
+        Ok(())
+    }
+}
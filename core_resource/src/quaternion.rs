@@ -1,6 +1,8 @@
-use crate::helpers::IntoF32;
+use std::ops::Mul;
 
-#[derive(Debug, Default)]
+use crate::{helpers::IntoF32, vector::Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quaternion {
     x: f32,
     y: f32,
@@ -9,6 +11,13 @@ pub struct Quaternion {
 }
 
 impl Quaternion {
+    pub const IDENTITY: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
     pub fn new(x: impl IntoF32, y: impl IntoF32, z: impl IntoF32, w: impl IntoF32) -> Self {
         Self {
             x: x.into_f32(),
@@ -33,6 +42,153 @@ impl Quaternion {
     pub fn w(&self) -> f32 {
         self.w
     }
+
+    /// Rotation of `angle` radians around `axis`
+    pub fn from_axis_angle(axis: impl Into<Vector3>, angle: f32) -> Self {
+        let axis = axis.into().normalize();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Self {
+            x: axis.x() * sin,
+            y: axis.y() * sin,
+            z: axis.z() * sin,
+            w: cos,
+        }
+    }
+
+    /// Converts Euler rotation in radians as returned from `rot()` (x is pitch, y is roll, z is yaw)
+    /// to quaternion, rotations are applied in the same order as in GTA: yaw, then pitch, then roll
+    ///
+    /// # Examples
+    /// ```rust
+    /// let rotation = altv::Quaternion::from_rot(vehicle.rot()?);
+    /// // direction in which vehicle is facing (including pitch)
+    /// let forward = rotation * altv::Vector3::new(0, 1, 0);
+    /// ```
+    pub fn from_rot(rot: impl Into<Vector3>) -> Self {
+        let rot = rot.into();
+        let pitch = Self::from_axis_angle((1, 0, 0), rot.x());
+        let roll = Self::from_axis_angle((0, 1, 0), rot.y());
+        let yaw = Self::from_axis_angle((0, 0, 1), rot.z());
+        yaw * pitch * roll
+    }
+
+    /// Converts quaternion to Euler rotation in radians which can be passed to `set_rot()`,
+    /// see [`from_rot`](#method.from_rot)
+    pub fn to_rot(self) -> Vector3 {
+        let Self { x, y, z, w } = self.normalize();
+
+        // elements of rotation matrix which are enough to extract yaw * pitch * roll angles
+        let m01 = 2.0 * (x * y - w * z);
+        let m11 = 1.0 - 2.0 * (x * x + z * z);
+        let m20 = 2.0 * (x * z - w * y);
+        let m21 = 2.0 * (y * z + w * x);
+        let m22 = 1.0 - 2.0 * (x * x + y * y);
+
+        let pitch = m21.clamp(-1.0, 1.0).asin();
+        Vector3::new(pitch, (-m20).atan2(m22), (-m01).atan2(m11))
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+
+    pub fn dot(&self, other: Quaternion) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns quaternion with length of 1, or identity quaternion if length is 0
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self {
+            x: self.x / length,
+            y: self.y / length,
+            z: self.z / length,
+            w: self.w / length,
+        }
+    }
+
+    /// Inverse rotation (for quaternions with length of 1)
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Spherical linear interpolation between `self` (`t` = 0.0) and `other` (`t` = 1.0)
+    pub fn slerp(&self, other: Quaternion, t: f32) -> Self {
+        let (from, mut to) = (self.normalize(), other.normalize());
+        let mut cos = from.dot(to);
+
+        // take the shortest path
+        if cos < 0.0 {
+            to = Self {
+                x: -to.x,
+                y: -to.y,
+                z: -to.z,
+                w: -to.w,
+            };
+            cos = -cos;
+        }
+
+        let (from_weight, to_weight) = if cos > 0.9995 {
+            // quaternions are too close, fall back to linear interpolation
+            (1.0 - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        Self {
+            x: from.x * from_weight + to.x * to_weight,
+            y: from.y * from_weight + to.y * to_weight,
+            z: from.z * from_weight + to.z * to_weight,
+            w: from.w * from_weight + to.w * to_weight,
+        }
+        .normalize()
+    }
+
+    /// Direction of Y axis (forward in GTA) after rotation
+    pub fn forward_vector(&self) -> Vector3 {
+        *self * Vector3::new(0, 1, 0)
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Combines rotations, `a * b` applies `b` first and then `a`
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+/// Rotates vector
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let t = axis.cross(rhs) * 2.0;
+        rhs + t * self.w + axis.cross(t)
+    }
 }
 
 impl<T: IntoF32> From<T> for Quaternion {
@@ -52,3 +208,52 @@ where
         Self::new(x, y, z, w)
     }
 }
+
+#[cfg(feature = "mint")]
+impl From<Quaternion> for mint::Quaternion<f32> {
+    fn from(value: Quaternion) -> Self {
+        Self {
+            v: mint::Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Quaternion<f32>> for Quaternion {
+    fn from(value: mint::Quaternion<f32>) -> Self {
+        Self::new(value.v.x, value.v.y, value.v.z, value.s)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Quaternion> for glam::Quat {
+    fn from(value: Quaternion) -> Self {
+        Self::from_xyzw(value.x, value.y, value.z, value.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Quat> for Quaternion {
+    fn from(value: glam::Quat) -> Self {
+        Self::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Quaternion> for nalgebra::Quaternion<f32> {
+    fn from(value: Quaternion) -> Self {
+        Self::new(value.w, value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Quaternion<f32>> for Quaternion {
+    fn from(value: nalgebra::Quaternion<f32>) -> Self {
+        Self::new(value.i, value.j, value.k, value.w)
+    }
+}
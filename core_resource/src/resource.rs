@@ -6,9 +6,9 @@ use std::{
 use core_shared::{ModuleHandlers, ResourceName};
 
 use crate::{
-    alt_resource, base_objects,
+    alt_resource, base_objects, commands, components,
     events::{self, connection_queue},
-    script_events, timers,
+    script_events, spatial, stop_hook, timers,
 };
 
 thread_local! {
@@ -31,6 +31,10 @@ pub struct Resource {
     pub extra_base_object_pools: RefCell<base_objects::extra_pools::ExtraPools>,
     pub connection_queue: RefCell<connection_queue::ConnectionQueueManager>,
     pub alt_resources: RefCell<alt_resource::AltResourceManager>,
+    pub commands: RefCell<commands::CommandManager>,
+    pub stop_hook: RefCell<stop_hook::StopHook>,
+    pub components: RefCell<components::ComponentStore>,
+    pub spatial_index: RefCell<spatial::SpatialIndex>,
 }
 
 macro_rules! with_resource {
@@ -131,11 +135,48 @@ impl Resource {
             return;
         }
 
-        self.base_objects.borrow_mut().on_remove(
+        let detached = self.base_objects.borrow_mut().on_remove(
             ptr,
             base_object_type,
             self.extra_base_object_pools.borrow_mut(),
         );
+        // user data and components are dropped after base objects borrow is released,
+        // so their `Drop` implementations can use base objects
+        drop(detached);
+
+        // events can be borrowed if base object was destroyed in event handler,
+        // in that case invalid handlers will be removed on the next meta change event
+        if let Ok(mut events) = self.events.try_borrow_mut() {
+            events.remove_invalid_meta_change_handlers();
+        }
+    }
+
+    pub fn on_stop(&self) {
+        logger::debug!("on_stop");
+
+        // take it out first so stop hook can freely use the resource
+        let stop_hook = self.stop_hook.borrow_mut().take();
+        if let Some(stop_hook) = stop_hook {
+            if let Err(error) = stop_hook() {
+                logger::error!("stop hook failed with error: {error:?}");
+            }
+        }
+
+        self.timer_schedule.borrow_mut().clear();
+        self.timers.borrow_mut().clear();
+
+        // see `on_base_object_destroy` for why user data and components are dropped separately
+        let detached = self
+            .base_objects
+            .borrow_mut()
+            .destroy_created(self.extra_base_object_pools.borrow_mut());
+        drop(detached);
+
+        let detached = self
+            .base_objects
+            .borrow_mut()
+            .remove_all_externally(self.extra_base_object_pools.borrow_mut());
+        drop(detached);
     }
 
     impl_borrow_mut_fn!(timers, timers::TimerManager);
@@ -160,4 +201,9 @@ impl Resource {
     impl_borrow_mut_fn!(connection_queue, connection_queue::ConnectionQueueManager);
     impl_borrow_fn!(alt_resources, alt_resource::AltResourceManager);
     impl_borrow_mut_fn!(alt_resources, alt_resource::AltResourceManager);
+    impl_borrow_mut_fn!(commands, commands::CommandManager);
+    impl_borrow_mut_fn!(stop_hook, stop_hook::StopHook);
+    impl_borrow_mut_fn!(components, components::ComponentStore);
+    impl_borrow_fn!(components, components::ComponentStore);
+    impl_borrow_mut_fn!(spatial_index, spatial::SpatialIndex);
 }
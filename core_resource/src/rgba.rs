@@ -1,4 +1,8 @@
-#[derive(Debug)]
+use std::{fmt::Display, str::FromStr};
+
+use crate::SomeResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RGBA {
     r: u8,
     g: u8,
@@ -7,7 +11,7 @@ pub struct RGBA {
 }
 
 impl RGBA {
-    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+    pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
         Self {
             r: red,
             g: green,
@@ -31,6 +35,167 @@ impl RGBA {
     pub fn a(&self) -> u8 {
         self.a
     }
+
+    /// Parses color from hex string: `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA` (`#` is optional)
+    ///
+    /// # Examples
+    /// ```rust
+    /// let color = altv::RGBA::from_hex("#FF8800AA")?;
+    /// assert_eq!(color, altv::RGBA::new(255, 136, 0, 170));
+    /// ```
+    pub fn from_hex(hex: &str) -> SomeResult<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.is_ascii() {
+            anyhow::bail!("Invalid hex color: {hex:?}");
+        }
+
+        let channel = |index: usize, size: usize| -> SomeResult<u8> {
+            let value = u8::from_str_radix(&digits[index * size..(index + 1) * size], 16)
+                .map_err(|_| anyhow::anyhow!("Invalid hex color: {hex:?}"))?;
+            // #RGB shorthand: "F" means "FF"
+            Ok(if size == 1 { value * 17 } else { value })
+        };
+
+        let (size, has_alpha) = match digits.len() {
+            3 => (1, false),
+            4 => (1, true),
+            6 => (2, false),
+            8 => (2, true),
+            _ => anyhow::bail!("Invalid hex color: {hex:?}"),
+        };
+
+        Ok(Self::new(
+            channel(0, size)?,
+            channel(1, size)?,
+            channel(2, size)?,
+            if has_alpha { channel(3, size)? } else { 255 },
+        ))
+    }
+
+    /// Formats color as `#RRGGBBAA`
+    pub fn to_hex(self) -> String {
+        format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        let (hue, max, min) = self.hue_max_min();
+        Hsv {
+            h: hue,
+            s: if max == 0.0 { 0.0 } else { (max - min) / max },
+            v: max,
+        }
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        let (hue, max, min) = self.hue_max_min();
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl { h: hue, s, l }
+    }
+
+    /// Linear interpolation between `self` (`t` = 0.0) and `other` (`t` = 1.0), including alpha
+    ///
+    /// # Examples
+    /// ```rust
+    /// // from green to red as health decreases
+    /// let color = altv::RGBA::HUD_GREEN.lerp(altv::RGBA::HUD_RED, 1.0 - health / 100.0);
+    /// ```
+    pub fn lerp(self, other: impl Into<RGBA>, t: f32) -> Self {
+        let other = other.into();
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+
+        Self::new(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
+    }
+
+    /// Returns the same color with different alpha
+    pub fn with_alpha(self, alpha: u8) -> Self {
+        Self { a: alpha, ..self }
+    }
+
+    /// Hue in degrees, max and min channels in 0.0..=1.0
+    fn hue_max_min(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (hue, max, min)
+    }
+
+    /// Converts chroma, hue and lightness offset (shared by HSV and HSL) to color
+    fn from_chroma(hue: f32, chroma: f32, offset: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |value: f32| ((value + offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        Self::new(channel(r), channel(g), channel(b), 255)
+    }
+}
+
+/// Colors of GTA HUD (`HUD_COLOUR_*` from `hudcolor.dat`)
+impl RGBA {
+    pub const HUD_PURE_WHITE: Self = Self::new(255, 255, 255, 255);
+    pub const HUD_WHITE: Self = Self::new(240, 240, 240, 255);
+    pub const HUD_BLACK: Self = Self::new(0, 0, 0, 255);
+    pub const HUD_GREY: Self = Self::new(155, 155, 155, 255);
+    pub const HUD_GREY_LIGHT: Self = Self::new(205, 205, 205, 255);
+    pub const HUD_GREY_DARK: Self = Self::new(77, 77, 77, 255);
+    pub const HUD_RED: Self = Self::new(224, 50, 50, 255);
+    pub const HUD_RED_LIGHT: Self = Self::new(240, 153, 153, 255);
+    pub const HUD_RED_DARK: Self = Self::new(112, 25, 25, 255);
+    pub const HUD_BLUE: Self = Self::new(93, 182, 229, 255);
+    pub const HUD_BLUE_LIGHT: Self = Self::new(174, 219, 242, 255);
+    pub const HUD_BLUE_DARK: Self = Self::new(47, 92, 115, 255);
+    pub const HUD_YELLOW: Self = Self::new(240, 200, 80, 255);
+    pub const HUD_YELLOW_LIGHT: Self = Self::new(254, 235, 169, 255);
+    pub const HUD_YELLOW_DARK: Self = Self::new(126, 107, 41, 255);
+    pub const HUD_ORANGE: Self = Self::new(255, 133, 85, 255);
+    pub const HUD_ORANGE_LIGHT: Self = Self::new(255, 194, 170, 255);
+    pub const HUD_ORANGE_DARK: Self = Self::new(127, 66, 42, 255);
+    pub const HUD_GREEN: Self = Self::new(114, 204, 114, 255);
+    pub const HUD_GREEN_LIGHT: Self = Self::new(185, 230, 185, 255);
+    pub const HUD_GREEN_DARK: Self = Self::new(57, 102, 57, 255);
+    pub const HUD_PURPLE: Self = Self::new(132, 102, 226, 255);
+    pub const HUD_PURPLE_LIGHT: Self = Self::new(192, 179, 239, 255);
+    pub const HUD_PURPLE_DARK: Self = Self::new(67, 57, 111, 255);
+    pub const HUD_PINK: Self = Self::new(203, 54, 148, 255);
+    pub const HUD_FREEMODE: Self = Self::new(45, 110, 185, 255);
+    pub const HUD_MICHAEL: Self = Self::new(101, 180, 212, 255);
+    pub const HUD_FRANKLIN: Self = Self::new(171, 237, 171, 255);
+    pub const HUD_TREVOR: Self = Self::new(255, 163, 87, 255);
 }
 
 impl From<(u8, u8, u8)> for RGBA {
@@ -44,3 +209,85 @@ impl From<(u8, u8, u8, u8)> for RGBA {
         Self::new(value.0, value.1, value.2, value.3)
     }
 }
+
+impl FromStr for RGBA {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> SomeResult<Self> {
+        Self::from_hex(s)
+    }
+}
+
+/// Formats color as `#RRGGBBAA`
+impl Display for RGBA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+/// Hue (0.0..360.0 degrees), saturation and value (0.0..=1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+/// Hue (0.0..360.0 degrees), saturation and lightness (0.0..=1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl From<Hsv> for RGBA {
+    fn from(Hsv { h, s, v }: Hsv) -> Self {
+        let chroma = v * s;
+        Self::from_chroma(h, chroma, v - chroma)
+    }
+}
+
+impl From<Hsl> for RGBA {
+    fn from(Hsl { h, s, l }: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_chroma(h, chroma, l - chroma / 2.0)
+    }
+}
+
+/// Anything that can be converted to [`RGBA`](struct.RGBA.html) in color setters and constructors:
+/// tuples, [`Hsv`](struct.Hsv.html), [`Hsl`](struct.Hsl.html) and hex strings (`"#FF8800"`), which can fail to parse
+///
+/// # Examples
+/// ```rust
+/// vehicle.set_neon_color("#FF00FF")?;
+/// vehicle.set_primary_color_rgb(altv::Hsv { h: 200.0, s: 0.8, v: 1.0 })?;
+/// vehicle.set_secondary_color_rgb((255, 0, 0))?;
+/// ```
+pub trait IntoRGBA {
+    fn into_rgba(self) -> SomeResult<RGBA>;
+}
+
+impl<T: Into<RGBA>> IntoRGBA for T {
+    fn into_rgba(self) -> SomeResult<RGBA> {
+        Ok(self.into())
+    }
+}
+
+impl IntoRGBA for &str {
+    fn into_rgba(self) -> SomeResult<RGBA> {
+        RGBA::from_hex(self)
+    }
+}
+
+impl IntoRGBA for String {
+    fn into_rgba(self) -> SomeResult<RGBA> {
+        RGBA::from_hex(&self)
+    }
+}
+
+impl IntoRGBA for &String {
+    fn into_rgba(self) -> SomeResult<RGBA> {
+        RGBA::from_hex(self)
+    }
+}
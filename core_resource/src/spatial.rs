@@ -0,0 +1,270 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{
+    base_objects::{
+        extra_pools::{AnyEntity, EntityPool},
+        network_object, ped, player, vehicle, ValidBaseObject,
+    },
+    resource::Resource,
+    vector::Vector3,
+    world_object::WorldObject,
+    SomeResult,
+};
+
+/// Size of grid cell in game units (cells are 2D, z is only checked when filtering)
+const CELL_SIZE: f32 = 50.0;
+
+type Cell = (i32, i32);
+
+#[derive(Debug)]
+struct Entry {
+    pos: [f32; 3],
+    entity: AnyEntity,
+}
+
+/// Grid of entity positions per dimension, refreshed once per tick
+#[derive(Debug, Default)]
+pub struct SpatialIndex {
+    /// index is built only after the first query so resources which don't use it
+    /// don't read positions of all entities every tick
+    enabled: bool,
+    dimensions: HashMap<i32, HashMap<Cell, Vec<Entry>>>,
+}
+
+impl SpatialIndex {
+    /// Called every tick
+    pub(crate) fn refresh(&mut self, entities: &EntityPool) {
+        if !self.enabled {
+            return;
+        }
+        self.rebuild(entities);
+    }
+
+    fn rebuild(&mut self, entities: &EntityPool) {
+        self.dimensions.clear();
+
+        for entity in entities.all() {
+            let (pos, dimension) = match read_pos_and_dimension(entity) {
+                Ok(v) => v,
+                Err(error) => {
+                    logger::debug!("failed to read position of entity: {entity:?} {error:?}");
+                    continue;
+                }
+            };
+            let pos = [pos.x(), pos.y(), pos.z()];
+
+            self.dimensions
+                .entry(dimension)
+                .or_default()
+                .entry(cell_of(pos[0], pos[1]))
+                .or_default()
+                .push(Entry {
+                    pos,
+                    entity: entity.clone(),
+                });
+        }
+    }
+
+    fn ensure_enabled(&mut self, entities: &EntityPool) {
+        if self.enabled {
+            return;
+        }
+        logger::debug!("enabling spatial index");
+        self.enabled = true;
+        self.rebuild(entities);
+    }
+
+    /// Returns entries whose x and y are inside of `min` and `max`
+    fn entries_in_area(
+        &self,
+        dimension: i32,
+        min: [f32; 2],
+        max: [f32; 2],
+    ) -> impl Iterator<Item = &Entry> {
+        let cells = self.dimensions.get(&dimension);
+        let (min_cell, max_cell) = (cell_of(min[0], min[1]), cell_of(max[0], max[1]));
+        // cells are saturated to `i32` bounds, so area of huge ranges (e.g. `f32::MAX`) doesn't fit in `i64`
+        let area_cells = (max_cell.0 as i64 - min_cell.0 as i64 + 1)
+            .checked_mul(max_cell.1 as i64 - min_cell.1 as i64 + 1);
+
+        // large areas (or huge ranges) are cheaper to check by iterating over occupied cells
+        let occupied = cells.map(|cells| cells.len() as i64).unwrap_or_default();
+        let by_area = matches!(area_cells, Some(area_cells) if area_cells <= occupied);
+
+        let area: Box<dyn Iterator<Item = &Vec<Entry>> + '_> = match cells {
+            None => Box::new(std::iter::empty()),
+            Some(cells) if by_area => Box::new(
+                (min_cell.0..=max_cell.0)
+                    .flat_map(move |x| (min_cell.1..=max_cell.1).map(move |y| (x, y)))
+                    .filter_map(|cell| cells.get(&cell)),
+            ),
+            Some(cells) => Box::new(
+                cells
+                    .iter()
+                    .filter(move |((x, y), _)| {
+                        (min_cell.0..=max_cell.0).contains(x)
+                            && (min_cell.1..=max_cell.1).contains(y)
+                    })
+                    .map(|(_, entries)| entries),
+            ),
+        };
+
+        area.flatten().filter(move |Entry { pos, .. }| {
+            (min[0]..=max[0]).contains(&pos[0]) && (min[1]..=max[1]).contains(&pos[1])
+        })
+    }
+}
+
+fn cell_of(x: f32, y: f32) -> Cell {
+    (
+        (x / CELL_SIZE).floor() as i32,
+        (y / CELL_SIZE).floor() as i32,
+    )
+}
+
+fn distance_squared(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+}
+
+fn read_pos_and_dimension(entity: &AnyEntity) -> SomeResult<(Vector3, i32)> {
+    Ok(match entity {
+        AnyEntity::Player(v) => (v.pos()?, v.dimension()?),
+        AnyEntity::Vehicle(v) => (v.pos()?, v.dimension()?),
+        AnyEntity::Ped(v) => (v.pos()?, v.dimension()?),
+        AnyEntity::NetworkObject(v) => (v.pos()?, v.dimension()?),
+    })
+}
+
+fn with_index<R>(f: impl FnOnce(&SpatialIndex) -> R) -> R {
+    Resource::with_spatial_index_mut(|mut index, resource| {
+        index.ensure_enabled(&resource.extra_base_object_pools.borrow().entity);
+        f(&index)
+    })
+}
+
+/// Entity types which can be returned from spatial queries
+pub trait SpatialEntity: Sized {
+    #[doc(hidden)]
+    fn from_entity(entity: &AnyEntity) -> Option<Rc<Self>>;
+}
+
+macro_rules! impl_spatial_entity {
+    ($( $pool: ident::$name: ident ),+ $(,)?) => { $(
+        impl SpatialEntity for $pool::$name {
+            fn from_entity(entity: &AnyEntity) -> Option<Rc<Self>> {
+                match entity {
+                    AnyEntity::$name(v) if v.valid() => Some(v.clone()),
+                    _ => None,
+                }
+            }
+        }
+    )+ };
+}
+
+impl_spatial_entity!(
+    player::Player,
+    vehicle::Vehicle,
+    ped::Ped,
+    network_object::NetworkObject,
+);
+
+/// Returns entities of type `T` in `range` of `pos` (3D distance) in the specified dimension.
+///
+/// Positions are read once per tick, so entities which were created or moved
+/// during the current tick may be missing or found at their previous position.
+///
+/// # Examples
+/// ```rust
+/// let pos = player.pos()?;
+/// let dimension = player.dimension()?;
+/// for vehicle in altv::spatial::in_range::<altv::Vehicle>(pos, 20.0, dimension) {
+///     vehicle.set_engine_on(false)?;
+/// }
+/// ```
+pub fn in_range<T: SpatialEntity>(
+    pos: impl Into<Vector3>,
+    range: f32,
+    dimension: i32,
+) -> Vec<Rc<T>> {
+    let pos = pos.into();
+    let pos = [pos.x(), pos.y(), pos.z()];
+
+    with_index(|index| {
+        index
+            .entries_in_area(
+                dimension,
+                [pos[0] - range, pos[1] - range],
+                [pos[0] + range, pos[1] + range],
+            )
+            .filter(|entry| distance_squared(entry.pos, pos) <= range * range)
+            .filter_map(|entry| T::from_entity(&entry.entity))
+            .collect()
+    })
+}
+
+/// Returns up to `limit` closest entities of type `T` in `range` of `pos`, sorted by distance
+///
+/// # Examples
+/// ```rust
+/// let closest = altv::spatial::closest::<altv::Player>(player.pos()?, 10.0, player.dimension()?, 2);
+/// // first one is player itself
+/// if let Some(other) = closest.get(1) {
+///     altv::log!("closest player: {}", other.name()?);
+/// }
+/// ```
+pub fn closest<T: SpatialEntity>(
+    pos: impl Into<Vector3>,
+    range: f32,
+    dimension: i32,
+    limit: usize,
+) -> Vec<Rc<T>> {
+    let pos = pos.into();
+    let pos = [pos.x(), pos.y(), pos.z()];
+
+    with_index(|index| {
+        let mut found: Vec<_> = index
+            .entries_in_area(
+                dimension,
+                [pos[0] - range, pos[1] - range],
+                [pos[0] + range, pos[1] + range],
+            )
+            .filter_map(|entry| {
+                let distance = distance_squared(entry.pos, pos);
+                if distance > range * range {
+                    return None;
+                }
+                Some((distance, T::from_entity(&entry.entity)?))
+            })
+            .collect();
+
+        found.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        found.truncate(limit);
+        found.into_iter().map(|(_, entity)| entity).collect()
+    })
+}
+
+/// Returns entities of type `T` inside of axis-aligned box from `min` to `max` in the specified dimension
+///
+/// # Examples
+/// ```rust
+/// let players = altv::spatial::in_box::<altv::Player>(
+///     (-100, -100, 0),
+///     (100, 100, 50),
+///     altv::DEFAULT_DIMENSION,
+/// );
+/// ```
+pub fn in_box<T: SpatialEntity>(
+    min: impl Into<Vector3>,
+    max: impl Into<Vector3>,
+    dimension: i32,
+) -> Vec<Rc<T>> {
+    let (min, max) = (min.into(), max.into());
+
+    with_index(|index| {
+        index
+            .entries_in_area(dimension, [min.x(), min.y()], [max.x(), max.y()])
+            .filter(|entry| (min.z()..=max.z()).contains(&entry.pos[2]))
+            .filter_map(|entry| T::from_entity(&entry.entity))
+            .collect()
+    })
+}
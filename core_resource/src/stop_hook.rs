@@ -0,0 +1,36 @@
+use std::fmt::Debug;
+
+use crate::{resource::Resource, IntoVoidResult, VoidResult};
+
+pub type StopHookCallback = Box<dyn FnOnce() -> VoidResult + 'static>;
+
+#[derive(Default)]
+pub struct StopHook {
+    callback: Option<StopHookCallback>,
+}
+
+impl StopHook {
+    pub fn set(&mut self, callback: StopHookCallback) {
+        if self.callback.replace(callback).is_some() {
+            logger::warn!("stop hook was set twice, overriding previous one");
+        }
+    }
+
+    pub fn take(&mut self) -> Option<StopHookCallback> {
+        self.callback.take()
+    }
+}
+
+impl Debug for StopHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StopHook {{ is_set: {} }}", self.callback.is_some())
+    }
+}
+
+/// Sets function that will be called when resource is stopping,
+/// before pending timers are dropped and base objects of this resource are destroyed
+pub fn set_stop_hook<V: IntoVoidResult>(callback: impl FnOnce() -> V + 'static) {
+    Resource::with_stop_hook_mut(|mut hook, _| {
+        hook.set(Box::new(move || callback().into_void_result()))
+    });
+}
@@ -1,3 +1,5 @@
+use mvalue_derive::{FromMValue, IntoMValue};
+
 use crate::{helpers::Hash, vector::Vector3};
 
 #[derive(Debug, Default)]
@@ -30,7 +32,8 @@ pub struct PlayerDateTime {
     pub second: i32,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
 pub struct PlayerHeadBlendData {
     pub shape_first_id: u32,
     pub shape_second_id: u32,
@@ -57,7 +60,8 @@ pub struct PlayerCloth {
     pub palette: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
 pub struct PlayerDlcCloth {
     pub drawable: u16,
     pub texture: u8,
@@ -71,14 +75,16 @@ pub struct PlayerProp {
     pub texture: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
 pub struct PlayerDlcProp {
     pub drawable: u8,
     pub texture: u8,
     pub dlc: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
 pub struct PlayerHeadOverlay {
     pub index: u8,
     pub opacity: f32,
@@ -178,7 +184,8 @@ impl std::ops::BitXor for AnimationFlags {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
 pub struct VehicleNeon {
     pub left: bool,
     pub right: bool,
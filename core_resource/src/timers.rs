@@ -46,6 +46,10 @@ impl ScheduleState {
 
         id
     }
+
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
 }
 
 #[derive(Debug, Default)]
@@ -81,6 +85,10 @@ impl TimerManager {
             self.timers.swap_remove(idx);
         }
     }
+
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
 }
 
 pub fn create_timer(callback: Box<dyn FnMut() -> VoidResult + 'static>, millis: u64, once: bool) {
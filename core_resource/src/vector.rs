@@ -1,6 +1,8 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
 use crate::helpers::IntoF32;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vector3 {
     x: f32,
     y: f32,
@@ -8,6 +10,12 @@ pub struct Vector3 {
 }
 
 impl Vector3 {
+    pub const ZERO: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
     pub fn new(x: impl IntoF32, y: impl IntoF32, z: impl IntoF32) -> Self {
         Self {
             x: x.into_f32(),
@@ -27,6 +35,46 @@ impl Vector3 {
     pub fn z(&self) -> f32 {
         self.z
     }
+
+    pub fn dot(&self, other: impl Into<Vector3>) -> f32 {
+        let other = other.into();
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: impl Into<Vector3>) -> Self {
+        let other = other.into();
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Distance between two points ignoring z axis
+    ///
+    /// # Examples
+    /// ```rust
+    /// if player.pos()?.distance_2d(shop_pos) < 5.0 {
+    ///     altv::log!("player is near the shop");
+    /// }
+    /// ```
+    pub fn distance_2d(&self, other: impl Into<Vector3>) -> f32 {
+        let other: Vector3 = other.into();
+        Vector2::from(*self).distance(Vector2::from(other))
+    }
+
+    /// Direction in which entity with the specified heading (`rot().z()`, in radians) is facing, z is always 0
+    ///
+    /// # Examples
+    /// ```rust
+    /// let pos = player.pos()?;
+    /// let heading = player.rot()?.z();
+    /// // 5 units in front of player
+    /// let spawn_pos = pos + altv::Vector3::forward_vector(heading) * 5.0;
+    /// ```
+    pub fn forward_vector(heading: f32) -> Self {
+        Self::new(-heading.sin(), heading.cos(), 0.0)
+    }
 }
 
 impl<T: IntoF32> From<T> for Vector3 {
@@ -46,13 +94,27 @@ where
     }
 }
 
-#[derive(Debug)]
+impl From<Vector3> for [f32; 3] {
+    fn from(value: Vector3) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<[f32; 3]> for Vector3 {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vector2 {
     x: f32,
     y: f32,
 }
 
 impl Vector2 {
+    pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
+
     pub fn new(x: impl IntoF32, y: impl IntoF32) -> Self {
         Self {
             x: x.into_f32(),
@@ -67,6 +129,46 @@ impl Vector2 {
     pub fn y(&self) -> f32 {
         self.y
     }
+
+    pub fn dot(&self, other: impl Into<Vector2>) -> f32 {
+        let other = other.into();
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Checks if point is inside of polygon (for example, points of [`ColShape::new_polygon`](struct.BaseObjectWrapper.html#method.new_polygon)),
+    /// edges of polygon are not included
+    ///
+    /// # Examples
+    /// ```rust
+    /// let zone = [
+    ///     altv::Vector2::new(0, 0),
+    ///     altv::Vector2::new(10, 0),
+    ///     altv::Vector2::new(10, 10),
+    ///     altv::Vector2::new(0, 10),
+    /// ];
+    /// let pos = player.pos()?;
+    /// if altv::Vector2::from(pos).in_polygon(&zone) {
+    ///     altv::log!("player is in the zone");
+    /// }
+    /// ```
+    pub fn in_polygon(&self, polygon: &[Vector2]) -> bool {
+        let Some(mut previous) = polygon.last() else {
+            return false;
+        };
+
+        let mut inside = false;
+        for current in polygon {
+            if (current.y > self.y) != (previous.y > self.y)
+                && self.x
+                    < (previous.x - current.x) * (self.y - current.y) / (previous.y - current.y)
+                        + current.x
+            {
+                inside = !inside;
+            }
+            previous = current;
+        }
+        inside
+    }
 }
 
 impl<T: IntoF32> From<T> for Vector2 {
@@ -84,3 +186,226 @@ where
         Self::new(x, y)
     }
 }
+
+impl From<Vector2> for [f32; 2] {
+    fn from(value: Vector2) -> Self {
+        [value.x, value.y]
+    }
+}
+
+impl From<[f32; 2]> for Vector2 {
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Drops z axis
+impl From<Vector3> for Vector2 {
+    fn from(value: Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+macro_rules! impl_vector_math {
+    ($( $name: ident { $( $field: ident ),+ } )+) => { $(
+        impl $name {
+            pub fn length(&self) -> f32 {
+                self.length_squared().sqrt()
+            }
+
+            pub fn length_squared(&self) -> f32 {
+                self.dot(*self)
+            }
+
+            pub fn distance(&self, other: impl Into<$name>) -> f32 {
+                (*self - other.into()).length()
+            }
+
+            /// Faster than [`distance`](#method.distance), useful for comparisons: `a.distance_squared(b) < range * range`
+            pub fn distance_squared(&self, other: impl Into<$name>) -> f32 {
+                (*self - other.into()).length_squared()
+            }
+
+            /// Returns vector with the same direction and length of 1, or zero vector if length is 0
+            pub fn normalize(&self) -> Self {
+                let length = self.length();
+                if length == 0.0 {
+                    return Self::ZERO;
+                }
+                *self / length
+            }
+
+            /// Linear interpolation between `self` (`t` = 0.0) and `other` (`t` = 1.0)
+            pub fn lerp(&self, other: impl Into<$name>, t: f32) -> Self {
+                let other: $name = other.into();
+                *self + (other - *self) * t
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $( $field: self.$field + rhs.$field, )+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $( $field: self.$field - rhs.$field, )+ }
+            }
+        }
+
+        impl Mul<f32> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self::Output {
+                Self { $( $field: self.$field * rhs, )+ }
+            }
+        }
+
+        impl Div<f32> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: f32) -> Self::Output {
+                Self { $( $field: self.$field / rhs, )+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $( $field: -self.$field, )+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<f32> for $name {
+            fn mul_assign(&mut self, rhs: f32) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<f32> for $name {
+            fn div_assign(&mut self, rhs: f32) {
+                *self = *self / rhs;
+            }
+        }
+    )+ };
+}
+
+impl_vector_math!(
+    Vector3 { x, y, z }
+    Vector2 { x, y }
+);
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use super::{Vector2, Vector3};
+
+    impl From<Vector3> for mint::Vector3<f32> {
+        fn from(value: Vector3) -> Self {
+            Self {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            }
+        }
+    }
+
+    impl From<mint::Vector3<f32>> for Vector3 {
+        fn from(value: mint::Vector3<f32>) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<Vector2> for mint::Vector2<f32> {
+        fn from(value: Vector2) -> Self {
+            Self {
+                x: value.x,
+                y: value.y,
+            }
+        }
+    }
+
+    impl From<mint::Vector2<f32>> for Vector2 {
+        fn from(value: mint::Vector2<f32>) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use super::{Vector2, Vector3};
+
+    impl From<Vector3> for glam::Vec3 {
+        fn from(value: Vector3) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<glam::Vec3> for Vector3 {
+        fn from(value: glam::Vec3) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<Vector2> for glam::Vec2 {
+        fn from(value: Vector2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<glam::Vec2> for Vector2 {
+        fn from(value: glam::Vec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl {
+    use super::{Vector2, Vector3};
+
+    impl From<Vector3> for nalgebra::Vector3<f32> {
+        fn from(value: Vector3) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<nalgebra::Vector3<f32>> for Vector3 {
+        fn from(value: nalgebra::Vector3<f32>) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<Vector2> for nalgebra::Vector2<f32> {
+        fn from(value: Vector2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<nalgebra::Vector2<f32>> for Vector2 {
+        fn from(value: nalgebra::Vector2<f32>) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
     meta::entity_stream_synced_meta::StreamSyncedEntityMeta,
     quaternion::Quaternion,
     resource::Resource,
-    rgba::RGBA,
+    rgba::{IntoRGBA, RGBA},
     sdk, structs,
     vector::Vector3,
     SomeResult, VoidResult,
@@ -171,12 +171,12 @@ impl vehicle::Vehicle {
         Ok(())
     }
 
-    pub fn get_mod(&self, category: u8) -> SomeResult<u8> {
-        Ok(unsafe { sdk::IVehicle::GetMod(self.raw_ptr()?, category) })
+    pub fn get_mod(&self, category: impl Into<u8>) -> SomeResult<u8> {
+        Ok(unsafe { sdk::IVehicle::GetMod(self.raw_ptr()?, category.into()) })
     }
 
-    pub fn get_mods_count(&self, category: u8) -> SomeResult<u8> {
-        Ok(unsafe { sdk::IVehicle::GetModsCount(self.raw_ptr()?, category) })
+    pub fn get_mods_count(&self, category: impl Into<u8>) -> SomeResult<u8> {
+        Ok(unsafe { sdk::IVehicle::GetModsCount(self.raw_ptr()?, category.into()) })
     }
 
     pub fn mod_kits_count(&self) -> SomeResult<u8> {
@@ -429,8 +429,8 @@ impl vehicle::Vehicle {
         ))
     }
 
-    pub fn set_primary_color_rgb(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color: RGBA = color.into();
+    pub fn set_primary_color_rgb(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
         unsafe {
             sdk::IVehicle::SetPrimaryColorRGB(
                 self.raw_ptr()?,
@@ -449,8 +449,8 @@ impl vehicle::Vehicle {
         ))
     }
 
-    pub fn set_secondary_color_rgb(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color: RGBA = color.into();
+    pub fn set_secondary_color_rgb(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
         unsafe {
             sdk::IVehicle::SetSecondaryColorRGB(
                 self.raw_ptr()?,
@@ -469,8 +469,8 @@ impl vehicle::Vehicle {
         ))
     }
 
-    pub fn set_tire_smoke_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color: RGBA = color.into();
+    pub fn set_tire_smoke_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
         unsafe {
             sdk::IVehicle::SetTireSmokeColor(
                 self.raw_ptr()?,
@@ -543,8 +543,8 @@ impl vehicle::Vehicle {
         ))
     }
 
-    pub fn set_neon_color(&self, color: impl Into<RGBA>) -> VoidResult {
-        let color: RGBA = color.into();
+    pub fn set_neon_color(&self, color: impl IntoRGBA) -> VoidResult {
+        let color = color.into_rgba()?;
         unsafe {
             sdk::IVehicle::SetNeonColor(self.raw_ptr()?, color.r(), color.g(), color.b(), color.a())
         }
@@ -828,8 +828,8 @@ impl vehicle::Vehicle {
         Ok(())
     }
 
-    pub fn set_mod(&self, category: u8, id: u8) -> SomeResult<bool> {
-        Ok(unsafe { sdk::IVehicle::SetMod(self.raw_ptr()?, category, id) })
+    pub fn set_mod(&self, category: impl Into<u8>, id: u8) -> SomeResult<bool> {
+        Ok(unsafe { sdk::IVehicle::SetMod(self.raw_ptr()?, category.into(), id) })
     }
 }
 
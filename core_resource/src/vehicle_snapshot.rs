@@ -0,0 +1,174 @@
+use std::rc::Rc;
+
+use mvalue_derive::{FromMValue, IntoMValue};
+
+use crate::{
+    base_objects::{extra_pools::Entity, vehicle},
+    helpers::Hash,
+    meta::{
+        base_object::{normal_meta::NormalBaseObjectMeta, synced_meta::SyncedBaseObjectMeta},
+        entity_stream_synced_meta::StreamSyncedEntityMeta,
+        snapshot::MetaSnapshot,
+    },
+    rgba::RGBA,
+    vector::Vector3,
+    world_object::WorldObject,
+    SomeResult, VoidResult,
+};
+
+/// State of vehicle which is enough to spawn the same vehicle again:
+/// position, appearance, health, damage, colors, mods and meta.
+///
+/// Can be converted to MValue and back, so it can be saved as JSON (for example, in garage)
+/// and restored with [`Vehicle::spawn_from_snapshot`](struct.BaseObjectWrapper.html#method.spawn_from_snapshot).
+///
+/// # Examples
+/// ```rust
+/// let json = altv::mvalue::MValue::from_value(vehicle.snapshot()?)?.to_json();
+/// vehicle.destroy()?;
+///
+/// let snapshot = altv::VehicleSnapshot::try_from(&altv::mvalue::MValue::from_json(&json))?;
+/// let vehicle = altv::Vehicle::spawn_from_snapshot(&snapshot)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct VehicleSnapshot {
+    pub model: Hash,
+    pub pos: Vector3,
+    pub rot: Vector3,
+    pub dimension: i32,
+
+    pub appearance_data: String,
+    pub game_state_data: String,
+    pub health_data: String,
+    pub damage_data: String,
+    pub script_data: String,
+
+    pub numberplate_text: String,
+    pub numberplate_index: u32,
+    pub primary_color: u8,
+    pub secondary_color: u8,
+    /// `None` if vehicle doesn't use custom primary color
+    pub primary_color_rgb: Option<RGBA>,
+    /// `None` if vehicle doesn't use custom secondary color
+    pub secondary_color_rgb: Option<RGBA>,
+    pub wheel_color: u8,
+    pub interior_color: u8,
+    pub dashboard_color: u8,
+    pub mod_kit: u8,
+    /// Index is mod category
+    pub mods: Vec<u8>,
+
+    pub meta: MetaSnapshot,
+    pub synced_meta: MetaSnapshot,
+    pub stream_synced_meta: MetaSnapshot,
+}
+
+impl VehicleSnapshot {
+    pub const MOD_CATEGORIES: u8 = 50;
+}
+
+/// # **`Vehicle snapshot implementation`**
+impl vehicle::Vehicle {
+    /// Captures state of vehicle, see [`VehicleSnapshot`](struct.VehicleSnapshot.html)
+    pub fn snapshot(self: &Rc<Self>) -> SomeResult<VehicleSnapshot> {
+        Ok(VehicleSnapshot {
+            model: self.model()?,
+            pos: self.pos()?,
+            rot: self.rot()?,
+            dimension: self.dimension()?,
+
+            appearance_data: self.appearance_data_base64()?,
+            game_state_data: self.game_state_base64()?,
+            health_data: self.health_data_base64()?,
+            damage_data: self.damage_data_base64()?,
+            script_data: self.script_data_base64()?,
+
+            numberplate_text: self.numberplate_text()?,
+            numberplate_index: self.numberplate_index()?,
+            primary_color: self.primary_color()?,
+            secondary_color: self.secondary_color()?,
+            primary_color_rgb: if self.is_primary_color_rgb()? {
+                Some(self.primary_color_rgb()?)
+            } else {
+                None
+            },
+            secondary_color_rgb: if self.is_secondary_color_rgb()? {
+                Some(self.secondary_color_rgb()?)
+            } else {
+                None
+            },
+            wheel_color: self.wheel_color()?,
+            interior_color: self.interior_color()?,
+            dashboard_color: self.dashboard_color()?,
+            mod_kit: self.mod_kit()?,
+            mods: (0..VehicleSnapshot::MOD_CATEGORIES)
+                .map(|category| self.get_mod(category))
+                .collect::<SomeResult<_>>()?,
+
+            meta: self.meta_snapshot()?,
+            synced_meta: self.synced_meta_snapshot()?,
+            stream_synced_meta: self.stream_synced_meta_snapshot()?,
+        })
+    }
+
+    /// Creates new vehicle with the state from [`VehicleSnapshot`](struct.VehicleSnapshot.html),
+    /// vehicle is destroyed if the state could not be applied
+    pub fn spawn_from_snapshot(
+        snapshot: &VehicleSnapshot,
+    ) -> SomeResult<vehicle::VehicleContainer> {
+        let vehicle = Self::new(snapshot.model, snapshot.pos, snapshot.rot)?;
+        if let Err(error) = Self::apply_snapshot(&vehicle, snapshot) {
+            if let Err(destroy_error) = vehicle.destroy() {
+                logger::error!(
+                    "failed to destroy vehicle spawned from snapshot: {destroy_error:?}"
+                );
+            }
+            return Err(error);
+        }
+        Ok(vehicle)
+    }
+
+    fn apply_snapshot(
+        vehicle: &vehicle::VehicleContainer,
+        snapshot: &VehicleSnapshot,
+    ) -> VoidResult {
+        vehicle.set_dimension(snapshot.dimension)?;
+
+        vehicle.set_appearance_data_base64(snapshot.appearance_data.as_str())?;
+        vehicle.set_game_state_base64(snapshot.game_state_data.as_str())?;
+        vehicle.set_health_data_base64(snapshot.health_data.as_str())?;
+        vehicle.set_damage_data_base64(snapshot.damage_data.as_str())?;
+        vehicle.set_script_data_base64(snapshot.script_data.as_str())?;
+
+        vehicle.set_numberplate_text(snapshot.numberplate_text.as_str())?;
+        vehicle.set_numberplate_index(snapshot.numberplate_index)?;
+        vehicle.set_primary_color(snapshot.primary_color)?;
+        vehicle.set_secondary_color(snapshot.secondary_color)?;
+        if let Some(color) = snapshot.primary_color_rgb {
+            vehicle.set_primary_color_rgb(color)?;
+        }
+        if let Some(color) = snapshot.secondary_color_rgb {
+            vehicle.set_secondary_color_rgb(color)?;
+        }
+        vehicle.set_wheel_color(snapshot.wheel_color)?;
+        vehicle.set_interior_color(snapshot.interior_color)?;
+        vehicle.set_dashboard_color(snapshot.dashboard_color)?;
+
+        // mods can only be set after mod kit, stock mods (0) are already set on new vehicle
+        if snapshot.mod_kit != 0 {
+            vehicle.set_mod_kit(snapshot.mod_kit)?;
+            for (category, id) in (0..VehicleSnapshot::MOD_CATEGORIES).zip(&snapshot.mods) {
+                if *id != 0 {
+                    vehicle.set_mod(category, *id)?;
+                }
+            }
+        }
+
+        vehicle.apply_meta_snapshot(&snapshot.meta)?;
+        vehicle.apply_synced_meta_snapshot(&snapshot.synced_meta)?;
+        vehicle.apply_stream_synced_meta_snapshot(&snapshot.stream_synced_meta)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+use mvalue_derive::{FromMValue, IntoMValue};
+
+use crate::{
+    base_objects::{extra_pools::Entity, vehicle},
+    rgba::RGBA,
+    structs::VehicleNeon,
+    vehicle_model_info::VehicleModelInfo,
+    SomeResult, VoidResult,
+};
+
+macro_rules! vehicle_mods {
+    ($( $(#[$attr: meta])* $name: ident = $id: literal, )+) => {
+        /// Category of vehicle mod (`eVehicleModType` in GTA),
+        /// can be passed to [`get_mod`](struct.BaseObjectWrapper.html#method.get_mod),
+        /// [`set_mod`](struct.BaseObjectWrapper.html#method.set_mod)
+        /// and [`get_mods_count`](struct.BaseObjectWrapper.html#method.get_mods_count) instead of `u8`
+        ///
+        /// # Examples
+        /// ```rust
+        /// vehicle.set_mod_kit(1)?;
+        /// let best_engine = vehicle.get_mods_count(altv::VehicleMod::Engine)?;
+        /// vehicle.set_mod(altv::VehicleMod::Engine, best_engine)?;
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, IntoMValue, FromMValue)]
+        #[mvalue(crate_name = "crate", rename_all = "camelCase")]
+        #[repr(u8)]
+        pub enum VehicleMod {
+            $( $(#[$attr])* $name = $id, )+
+        }
+
+        impl VehicleMod {
+            pub const ALL: &'static [VehicleMod] = &[$( Self::$name, )+];
+        }
+
+        impl TryFrom<u8> for VehicleMod {
+            type Error = anyhow::Error;
+
+            fn try_from(value: u8) -> SomeResult<Self> {
+                match value {
+                    $( $id => Ok(Self::$name), )+
+                    _ => anyhow::bail!("Unknown vehicle mod category: {value}"),
+                }
+            }
+        }
+    };
+}
+
+vehicle_mods!(
+    Spoiler = 0,
+    FrontBumper = 1,
+    RearBumper = 2,
+    SideSkirt = 3,
+    Exhaust = 4,
+    Frame = 5,
+    Grille = 6,
+    Hood = 7,
+    LeftFender = 8,
+    RightFender = 9,
+    Roof = 10,
+    Engine = 11,
+    Brakes = 12,
+    Transmission = 13,
+    Horn = 14,
+    Suspension = 15,
+    Armor = 16,
+    /// Toggle mod, unused in GTA
+    Nitrous = 17,
+    /// Toggle mod
+    Turbo = 18,
+    /// Toggle mod, unused in GTA
+    Subwoofer = 19,
+    /// Toggle mod, color is set with `set_tire_smoke_color`
+    TireSmoke = 20,
+    /// Toggle mod, unused in GTA
+    Hydraulics = 21,
+    /// Toggle mod, color is set with `set_headlight_color`
+    XenonLights = 22,
+    /// Set with `set_wheels`
+    FrontWheels = 23,
+    /// Rear wheels of motorcycles, set with `set_rear_wheels`
+    RearWheels = 24,
+    PlateHolder = 25,
+    VanityPlate = 26,
+    TrimDesign = 27,
+    Ornaments = 28,
+    Dashboard = 29,
+    DialDesign = 30,
+    DoorSpeakers = 31,
+    Seats = 32,
+    SteeringWheel = 33,
+    ShiftLever = 34,
+    Plaques = 35,
+    Speakers = 36,
+    Trunk = 37,
+    /// Hydraulics of lowriders (`VMT_HYDRO`)
+    HydraulicPump = 38,
+    EngineBlock = 39,
+    AirFilter = 40,
+    Struts = 41,
+    ArchCover = 42,
+    Aerials = 43,
+    Trim = 44,
+    Tank = 45,
+    Windows = 46,
+    /// Unused in GTA
+    RightDoor = 47,
+    Livery = 48,
+    Lightbar = 49,
+);
+
+impl VehicleMod {
+    /// Toggle mods can only be turned on (`1`) or off (`0`)
+    pub fn is_toggle(self) -> bool {
+        (17..=22).contains(&(self as u8))
+    }
+
+    /// Wheels are stored in [`Tuning`](struct.Tuning.html) separately, because they also have wheel type
+    fn is_wheels(self) -> bool {
+        matches!(self, Self::FrontWheels | Self::RearWheels)
+    }
+}
+
+impl From<VehicleMod> for u8 {
+    fn from(value: VehicleMod) -> Self {
+        value as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct TuningMod {
+    pub category: VehicleMod,
+    pub id: u8,
+}
+
+/// Visual and performance modifications of vehicle: mods, wheels, colors, neon, window tint and liveries.
+///
+/// Can be converted to MValue and back, so presets can be sent to client or saved as JSON.
+///
+/// # Examples
+/// ```rust
+/// let tuning = altv::Tuning {
+///     mod_kit: 1,
+///     mods: vec![
+///         altv::TuningMod { category: altv::VehicleMod::Engine, id: 4 },
+///         altv::TuningMod { category: altv::VehicleMod::Turbo, id: 1 },
+///     ],
+///     window_tint: 1,
+///     ..other_vehicle.tuning()?
+/// };
+/// vehicle.apply_tuning(&tuning)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, IntoMValue, FromMValue)]
+#[mvalue(crate_name = "crate", rename_all = "camelCase")]
+pub struct Tuning {
+    /// `0` means no mod kit, mods can't be applied without it
+    pub mod_kit: u8,
+    /// Mods which are not stock (`0`), wheels are set with `wheel_*` fields
+    pub mods: Vec<TuningMod>,
+    pub wheel_type: u8,
+    pub wheel_variation: u8,
+    pub rear_wheel_variation: u8,
+    pub custom_tires: bool,
+    pub primary_color: u8,
+    pub secondary_color: u8,
+    /// `None` if vehicle doesn't use custom primary color
+    pub primary_color_rgb: Option<RGBA>,
+    /// `None` if vehicle doesn't use custom secondary color
+    pub secondary_color_rgb: Option<RGBA>,
+    pub wheel_color: u8,
+    pub interior_color: u8,
+    pub dashboard_color: u8,
+    /// `None` if tire smoke color is not custom
+    pub tire_smoke_color: Option<RGBA>,
+    pub headlight_color: u8,
+    pub neon: VehicleNeon,
+    pub neon_color: RGBA,
+    pub window_tint: u8,
+    pub livery: u8,
+    pub roof_livery: u8,
+}
+
+impl Tuning {
+    /// Returns id of mod in the category, `0` (stock) if it's not in [`mods`](#structfield.mods)
+    pub fn get_mod(&self, category: VehicleMod) -> u8 {
+        self.mods
+            .iter()
+            .find(|v| v.category == category)
+            .map_or(0, |v| v.id)
+    }
+
+    /// Sets id of mod in the category, `0` removes the category from [`mods`](#structfield.mods)
+    pub fn set_mod(&mut self, category: VehicleMod, id: u8) {
+        self.mods.retain(|v| v.category != category);
+        if id != 0 {
+            self.mods.push(TuningMod { category, id });
+        }
+    }
+}
+
+/// # **`Vehicle tuning implementation`**
+impl vehicle::Vehicle {
+    /// Reads everything that is saved in [`Tuning`](struct.Tuning.html)
+    pub fn tuning(&self) -> SomeResult<Tuning> {
+        let mut mods = vec![];
+        if self.mod_kit()? != 0 {
+            for category in VehicleMod::ALL {
+                if category.is_wheels() {
+                    continue;
+                }
+                let id = self.get_mod(*category)?;
+                if id != 0 {
+                    mods.push(TuningMod {
+                        category: *category,
+                        id,
+                    });
+                }
+            }
+        }
+
+        Ok(Tuning {
+            mod_kit: self.mod_kit()?,
+            mods,
+            wheel_type: self.wheel_type()?,
+            wheel_variation: self.wheel_variation()?,
+            rear_wheel_variation: self.rear_wheel_variation()?,
+            custom_tires: self.custom_tires()?,
+            primary_color: self.primary_color()?,
+            secondary_color: self.secondary_color()?,
+            primary_color_rgb: if self.is_primary_color_rgb()? {
+                Some(self.primary_color_rgb()?)
+            } else {
+                None
+            },
+            secondary_color_rgb: if self.is_secondary_color_rgb()? {
+                Some(self.secondary_color_rgb()?)
+            } else {
+                None
+            },
+            wheel_color: self.wheel_color()?,
+            interior_color: self.interior_color()?,
+            dashboard_color: self.dashboard_color()?,
+            tire_smoke_color: if self.is_tire_smoke_color_custom()? {
+                Some(self.tire_smoke_color()?)
+            } else {
+                None
+            },
+            headlight_color: self.headlight_color()?,
+            neon: self.neon_active()?,
+            neon_color: self.neon_color()?,
+            window_tint: self.window_tint()?,
+            livery: self.livery()?,
+            roof_livery: self.roof_livery()?,
+        })
+    }
+
+    /// Applies [`Tuning`](struct.Tuning.html), mods which are not in the tuning are reset to stock.
+    ///
+    /// Mod kit is checked against [`VehicleModelInfo::mod_kits`](struct.VehicleModelInfo.html#structfield.mod_kits)
+    /// and mods against [`get_mods_count`](#method.get_mods_count) before any mod is changed,
+    /// error is returned if vehicle doesn't support them.
+    /// Count of mods depends on mod kit, so the mod kit is set before mods are checked
+    /// and the previous one is restored if they are not available.
+    pub fn apply_tuning(&self, tuning: &Tuning) -> VoidResult {
+        if tuning.mod_kit != 0 {
+            let model = self.model()?;
+            let Some(model_info) = VehicleModelInfo::get_by_hash(model) else {
+                anyhow::bail!("Unknown vehicle model: {model}");
+            };
+            let available = model_info
+                .mod_kits
+                .get(tuning.mod_kit as usize - 1)
+                .copied()
+                .unwrap_or(false);
+            if !available {
+                anyhow::bail!(
+                    "Mod kit {} is not available for vehicle model: {}",
+                    tuning.mod_kit,
+                    model_info.title
+                );
+            }
+        } else if !tuning.mods.is_empty() {
+            anyhow::bail!("Mods can't be applied without mod kit");
+        }
+
+        let previous_mod_kit = self.mod_kit()?;
+        if previous_mod_kit != tuning.mod_kit {
+            self.change_mod_kit(tuning.mod_kit)?;
+        }
+        if let Err(error) = self.check_tuning_mods(tuning) {
+            if previous_mod_kit != tuning.mod_kit {
+                self.change_mod_kit(previous_mod_kit)?;
+            }
+            return Err(error);
+        }
+
+        if tuning.mod_kit != 0 {
+            for category in VehicleMod::ALL {
+                if category.is_wheels() {
+                    continue;
+                }
+                let id = tuning.get_mod(*category);
+                if self.get_mod(*category)? != id && !self.set_mod(*category, id)? {
+                    anyhow::bail!("Failed to set mod {id} of category {category:?}");
+                }
+            }
+        }
+
+        self.set_wheels(tuning.wheel_type, tuning.wheel_variation)?;
+        self.set_rear_wheels(tuning.rear_wheel_variation)?;
+        self.set_custom_tires(tuning.custom_tires)?;
+
+        self.set_primary_color(tuning.primary_color)?;
+        self.set_secondary_color(tuning.secondary_color)?;
+        if let Some(color) = tuning.primary_color_rgb {
+            self.set_primary_color_rgb(color)?;
+        }
+        if let Some(color) = tuning.secondary_color_rgb {
+            self.set_secondary_color_rgb(color)?;
+        }
+        self.set_wheel_color(tuning.wheel_color)?;
+        self.set_interior_color(tuning.interior_color)?;
+        self.set_dashboard_color(tuning.dashboard_color)?;
+        if let Some(color) = tuning.tire_smoke_color {
+            self.set_tire_smoke_color(color)?;
+        }
+        self.set_headlight_color(tuning.headlight_color)?;
+
+        let VehicleNeon {
+            left,
+            right,
+            front,
+            back,
+        } = tuning.neon;
+        self.set_neon_active(left, right, front, back)?;
+        self.set_neon_color(tuning.neon_color)?;
+
+        self.set_window_tint(tuning.window_tint)?;
+        self.set_livery(tuning.livery)?;
+        self.set_roof_livery(tuning.roof_livery)?;
+
+        Ok(())
+    }
+
+    fn change_mod_kit(&self, mod_kit: u8) -> VoidResult {
+        if !self.set_mod_kit(mod_kit)? {
+            anyhow::bail!("Failed to set mod kit: {mod_kit}");
+        }
+        Ok(())
+    }
+
+    /// Must be called after mod kit of the tuning is set
+    fn check_tuning_mods(&self, tuning: &Tuning) -> VoidResult {
+        for TuningMod { category, id } in &tuning.mods {
+            let max = if category.is_toggle() {
+                1
+            } else {
+                self.get_mods_count(*category)?
+            };
+            if *id > max {
+                anyhow::bail!(
+                    "Mod {id} of category {category:?} is not available, vehicle has {max} mods in this category"
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ pub struct ResourceHandlers {
         Option<Box<dyn Fn(altv_sdk::BaseObjectMutPtr, altv_sdk::BaseObjectType) + 'static>>,
     pub on_base_object_destroy:
         Option<Box<dyn Fn(altv_sdk::BaseObjectMutPtr, altv_sdk::BaseObjectType) + 'static>>,
+    pub on_stop: Option<Box<dyn Fn() + 'static>>,
 }
 
 impl Debug for ResourceHandlers {
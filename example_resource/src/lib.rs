@@ -19,7 +19,7 @@ fn main() {
         // dbg!(altv::meta::keys());
         // dbg!(altv::meta::synced_keys());
 
-        // let ch = altv::Checkpoint::new(0, 0, 10.0, 10.0, (255, 255, 255), 10);
+        // let ch = altv::Checkpoint::new(0, 0, 10.0, 10.0, (255, 255, 255), 10)?;
 
         // dbg!(ch.stream_synced_meta_keys()?);
 
@@ -33,7 +33,7 @@ fn main() {
 
         // ch.synced_meta_entry("synced_meta_keys")?.set(true)?;
 
-        // let marker = altv::Marker::new(altv::MarkerType::Markerarrow, 0, (255, 255, 255));
+        // let marker = altv::Marker::new(altv::MarkerType::Markerarrow, 0, (255, 255, 255))?;
 
         // marker.synced_meta_entry("synced_meta_keys")?.set(true)?;
 
@@ -0,0 +1,749 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Data, DeriveInput, Fields};
+
+/// Implements conversion of struct or enum to MValue (`TryFrom<T> for Serializable`),
+/// so it can be used in `emit_client!`, meta setters, `mvalue::list!`, etc.
+///
+/// - Structs with named fields are converted to dict
+/// - Tuple structs are converted to list (or to the inner value if there is only one field)
+/// - Unit structs are converted to `null`
+/// - Enums, see "Enum tagging" below
+///
+/// ## Example
+/// ```
+/// #[derive(altv::mvalue::IntoMValue, altv::mvalue::FromMValue)]
+/// #[mvalue(rename_all = "camelCase")]
+/// struct Inventory {
+///     max_weight: u32, // sent as "maxWeight"
+///     items: Vec<String>,
+///     #[mvalue(skip)]
+///     dirty: bool, // not sent and set to `Default::default()` when received
+///     #[mvalue(default)]
+///     level: u8, // can be missing when received
+///     #[mvalue(rename = "owner_id")]
+///     owner: u32,
+/// }
+///
+/// altv::events::emit_client!("inventory", player, inventory)?;
+/// ```
+///
+/// ## Attributes
+/// Container:
+/// - `#[mvalue(rename_all = "...")]` renames all fields (or variants of enum), supported cases:
+///   `camelCase`, `PascalCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, `lowercase`, `UPPERCASE`
+/// - `#[mvalue(tag = "...")]` and `#[mvalue(content = "...")]`, see "Enum tagging"
+/// - `#[mvalue(crate_name = "...")]` if `altv` crate is renamed, same as in `altv::main`
+///
+/// Field:
+/// - `#[mvalue(rename = "...")]`
+/// - `#[mvalue(skip)]` field is not converted and set to `Default::default()` when received
+/// - `#[mvalue(default)]` field can be missing (or `null`) when received, `Default::default()` is used then
+///
+/// Variant:
+/// - `#[mvalue(rename = "...")]`
+/// - `#[mvalue(rename_all = "...")]` renames fields of the variant
+///
+/// ## Enum tagging
+/// By default enums are externally tagged (same as in serde):
+/// unit variants are converted to string (`"Variant"`) and other variants to dict: `{ "Variant": value }`.
+///
+/// With `#[mvalue(tag = "type")]` enum is internally tagged: `{ "type": "Variant", ...fields }`,
+/// only unit and struct variants are supported.
+///
+/// With `#[mvalue(tag = "type", content = "value")]` enum is adjacently tagged:
+/// `{ "type": "Variant", "value": value }` (unit variants have no `"value"` key).
+#[proc_macro_derive(IntoMValue, attributes(mvalue))]
+pub fn into_mvalue(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let container = ContainerAttrs::parse(&input.attrs);
+    let krate = container.crate_name();
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, convert) = fields_into(
+                &data.fields,
+                &container.rename_all,
+                &krate,
+                quote! { #name },
+            );
+            quote! {
+                let #pattern = value;
+                #convert
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_attrs = VariantAttrs::parse(&variant.attrs);
+                let variant_name = variant_attrs.name(&variant.ident, &container.rename_all);
+                let ident = &variant.ident;
+                let (pattern, convert) = fields_into(
+                    &variant.fields,
+                    &variant_attrs.rename_all,
+                    &krate,
+                    quote! { #name::#ident },
+                );
+                let unit = matches!(variant.fields, Fields::Unit);
+
+                let convert = match (&container.tag, &container.content) {
+                    (None, _) if unit => quote! {
+                        #krate::__internal::Serializable::try_from(#variant_name)
+                    },
+                    (None, _) => quote! {
+                        let mut dict = std::collections::HashMap::new();
+                        dict.insert(#variant_name.to_string(), { #convert }?);
+                        #krate::__internal::Serializable::try_from(dict)
+                    },
+                    (Some(tag), None) => {
+                        let inserts = match &variant.fields {
+                            Fields::Named(fields) => {
+                                named_fields_into_dict(fields, &variant_attrs.rename_all, &krate)
+                            }
+                            Fields::Unit => quote! {},
+                            Fields::Unnamed(_) => panic!(
+                                "internally tagged enums support only unit and struct variants"
+                            ),
+                        };
+                        quote! {
+                            let mut dict = std::collections::HashMap::new();
+                            dict.insert(
+                                #tag.to_string(),
+                                #krate::__internal::Serializable::try_from(#variant_name)?,
+                            );
+                            #inserts
+                            #krate::__internal::Serializable::try_from(dict)
+                        }
+                    }
+                    (Some(tag), Some(content)) => {
+                        let insert_content = if unit {
+                            quote! {}
+                        } else {
+                            quote! { dict.insert(#content.to_string(), { #convert }?); }
+                        };
+                        quote! {
+                            let mut dict = std::collections::HashMap::new();
+                            dict.insert(
+                                #tag.to_string(),
+                                #krate::__internal::Serializable::try_from(#variant_name)?,
+                            );
+                            #insert_content
+                            #krate::__internal::Serializable::try_from(dict)
+                        }
+                    }
+                };
+
+                quote! {
+                    #pattern => { #convert }
+                }
+            });
+
+            quote! {
+                match value {
+                    #( #arms )*
+                }
+            }
+        }
+        Data::Union(_) => panic!("IntoMValue cannot be derived for unions"),
+    };
+
+    let generics = add_bounds(
+        &input.generics,
+        quote! { std::convert::TryInto<#krate::__internal::Serializable, Error = #krate::__internal::anyhow::Error> },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics std::convert::TryFrom<#name #ty_generics> for #krate::__internal::Serializable #where_clause {
+            type Error = #krate::__internal::anyhow::Error;
+
+            #[allow(unused_variables)]
+            fn try_from(value: #name #ty_generics) -> #krate::__internal::SomeResult<Self> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Implements conversion of MValue to struct or enum (`FromMValue` and `TryFrom<&MValue>`),
+/// so it can be used with typed meta keys, event args, etc.
+///
+/// See [`IntoMValue`](derive.IntoMValue.html) for the description of attributes and encoding.
+///
+/// ## Example
+/// ```
+/// #[derive(altv::mvalue::FromMValue)]
+/// #[mvalue(tag = "type", rename_all = "camelCase")]
+/// enum Request {
+///     BuyItem { item_id: u32 },
+///     Logout,
+/// }
+///
+/// altv::events::on_client("request", |c| {
+///     let request = Request::try_from(c.args.get(0)?)?;
+///     Ok(())
+/// });
+/// ```
+#[proc_macro_derive(FromMValue, attributes(mvalue))]
+pub fn from_mvalue(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let container = ContainerAttrs::parse(&input.attrs);
+    let krate = container.crate_name();
+    let name = &input.ident;
+    let type_name = name.to_string();
+
+    let body = match &input.data {
+        Data::Struct(data) => fields_from(
+            &data.fields,
+            &container.rename_all,
+            &krate,
+            quote! { #name },
+            &type_name,
+        ),
+        Data::Enum(data) => {
+            let variants: Vec<_> = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_attrs = VariantAttrs::parse(&variant.attrs);
+                    let variant_name = variant_attrs.name(&variant.ident, &container.rename_all);
+                    let ident = &variant.ident;
+                    let unit = matches!(variant.fields, Fields::Unit);
+                    let variant_type_name = format!("{type_name}::{ident}");
+                    (
+                        variant,
+                        variant_attrs,
+                        variant_name,
+                        ident,
+                        unit,
+                        variant_type_name,
+                    )
+                })
+                .collect();
+
+            match (&container.tag, &container.content) {
+                (None, _) => {
+                    let unit_arms = variants.iter().filter(|v| v.4).map(
+                        |(_, _, variant_name, ident, ..)| quote! { #variant_name => Ok(#name::#ident), },
+                    );
+                    let other_arms = variants.iter().filter(|v| !v.4).map(
+                        |(variant, variant_attrs, variant_name, ident, _, variant_type_name)| {
+                            let convert = fields_from(
+                                &variant.fields,
+                                &variant_attrs.rename_all,
+                                &krate,
+                                quote! { #name::#ident },
+                                variant_type_name,
+                            );
+                            quote! {
+                                #variant_name => {
+                                    let value = content;
+                                    #convert
+                                }
+                            }
+                        },
+                    );
+                    quote! {
+                        match value {
+                            #krate::__internal::MValue::String(variant) => match variant.as_str() {
+                                #( #unit_arms )*
+                                variant => #krate::__internal::anyhow::bail!(
+                                    "Unknown unit variant: {variant:?} of enum: {}", #type_name
+                                ),
+                            },
+                            #krate::__internal::MValue::Dict(dict) if dict.len() == 1 => {
+                                let (variant, content) = dict.iter().next().unwrap();
+                                match variant.as_str() {
+                                    #( #other_arms )*
+                                    variant => #krate::__internal::anyhow::bail!(
+                                        "Unknown variant: {variant:?} of enum: {}", #type_name
+                                    ),
+                                }
+                            }
+                            value => #krate::__internal::anyhow::bail!(
+                                "Expected mvalue of type: {} (string or dict with one key), received: {value:?}",
+                                #type_name
+                            ),
+                        }
+                    }
+                }
+                (Some(tag), content) => {
+                    let arms = variants.iter().map(
+                        |(variant, variant_attrs, variant_name, ident, unit, variant_type_name)| {
+                            let convert = if *unit {
+                                quote! { Ok(#name::#ident) }
+                            } else if let Some(content) = content {
+                                let convert = fields_from(
+                                    &variant.fields,
+                                    &variant_attrs.rename_all,
+                                    &krate,
+                                    quote! { #name::#ident },
+                                    variant_type_name,
+                                );
+                                quote! {
+                                    let Some(value) = dict.get(#content) else {
+                                        #krate::__internal::anyhow::bail!(
+                                            "Missing key: {:?} of enum: {}", #content, #type_name
+                                        );
+                                    };
+                                    #convert
+                                }
+                            } else {
+                                let Fields::Named(fields) = &variant.fields else {
+                                    panic!("internally tagged enums support only unit and struct variants");
+                                };
+                                named_fields_from_dict(
+                                    fields,
+                                    &variant_attrs.rename_all,
+                                    &krate,
+                                    quote! { #name::#ident },
+                                    variant_type_name,
+                                )
+                            };
+                            quote! {
+                                #variant_name => { #convert }
+                            }
+                        },
+                    );
+                    quote! {
+                        let #krate::__internal::MValue::Dict(dict) = value else {
+                            #krate::__internal::anyhow::bail!(
+                                "Expected mvalue of type: {} (dict), received: {value:?}", #type_name
+                            );
+                        };
+                        let Some(#krate::__internal::MValue::String(variant)) = dict.get(#tag) else {
+                            #krate::__internal::anyhow::bail!(
+                                "Expected string tag: {:?} of enum: {}", #tag, #type_name
+                            );
+                        };
+                        match variant.as_str() {
+                            #( #arms )*
+                            variant => #krate::__internal::anyhow::bail!(
+                                "Unknown variant: {variant:?} of enum: {}", #type_name
+                            ),
+                        }
+                    }
+                }
+            }
+        }
+        Data::Union(_) => panic!("FromMValue cannot be derived for unions"),
+    };
+
+    let generics = add_bounds(&input.generics, quote! { #krate::__internal::FromMValue });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut try_from_generics = generics.clone();
+    try_from_generics
+        .params
+        .insert(0, syn::parse_quote! { '__mvalue });
+    let (try_from_impl_generics, ..) = try_from_generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #krate::__internal::FromMValue for #name #ty_generics #where_clause {
+            fn from_mvalue(value: &#krate::__internal::MValue) -> #krate::__internal::SomeResult<Self> {
+                #body
+            }
+        }
+
+        impl #try_from_impl_generics std::convert::TryFrom<&'__mvalue #krate::__internal::MValue>
+            for #name #ty_generics #where_clause
+        {
+            type Error = #krate::__internal::anyhow::Error;
+
+            fn try_from(value: &'__mvalue #krate::__internal::MValue) -> #krate::__internal::SomeResult<Self> {
+                <Self as #krate::__internal::FromMValue>::from_mvalue(value)
+            }
+        }
+    }
+    .into()
+}
+
+/// Returns pattern destructuring value into `__field_*` bindings and expression converting them to `Serializable`
+fn fields_into(
+    fields: &Fields,
+    rename_all: &Option<String>,
+    krate: &syn::Ident,
+    path: TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    match fields {
+        Fields::Named(named) => {
+            let bindings = named.named.iter().map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let binding = format_ident!("__field_{}", ident.unraw());
+                quote! { #ident: #binding }
+            });
+            let inserts = named_fields_into_dict(named, rename_all, krate);
+            (
+                quote! { #path { #( #bindings ),* } },
+                quote! {
+                    let mut dict = std::collections::HashMap::new();
+                    #inserts
+                    #krate::__internal::Serializable::try_from(dict)
+                },
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            let bindings: Vec<_> = (0..unnamed.unnamed.len())
+                .map(|index| format_ident!("__field_{index}"))
+                .collect();
+            let not_skipped: Vec<_> = unnamed
+                .unnamed
+                .iter()
+                .zip(&bindings)
+                .filter(|(field, _)| !FieldAttrs::parse(&field.attrs).skip)
+                .map(|(_, binding)| binding)
+                .collect();
+
+            let convert = if let [single] = unnamed.unnamed.iter().collect::<Vec<_>>().as_slice() {
+                if FieldAttrs::parse(&single.attrs).skip {
+                    panic!("the only field of tuple struct cannot be skipped");
+                }
+                quote! { std::convert::TryInto::<#krate::__internal::Serializable>::try_into(__field_0) }
+            } else {
+                quote! {
+                    let list: Vec<#krate::__internal::Serializable> = vec![
+                        #( std::convert::TryInto::<#krate::__internal::Serializable>::try_into(#not_skipped)?, )*
+                    ];
+                    #krate::__internal::Serializable::try_from(list)
+                }
+            };
+            (quote! { #path( #( #bindings ),* ) }, convert)
+        }
+        Fields::Unit => (
+            quote! { #path },
+            quote! { #krate::__internal::Serializable::try_from(()) },
+        ),
+    }
+}
+
+fn named_fields_into_dict(
+    fields: &syn::FieldsNamed,
+    rename_all: &Option<String>,
+    krate: &syn::Ident,
+) -> TokenStream2 {
+    let inserts = fields.named.iter().filter_map(|field| {
+        let attrs = FieldAttrs::parse(&field.attrs);
+        if attrs.skip {
+            return None;
+        }
+        let ident = field.ident.as_ref().unwrap();
+        let binding = format_ident!("__field_{}", ident.unraw());
+        let key = attrs.name(ident, rename_all);
+        Some(quote! {
+            dict.insert(
+                #key.to_string(),
+                std::convert::TryInto::<#krate::__internal::Serializable>::try_into(#binding).map_err(|error| {
+                    error.context(format!("Failed to convert field: {:?}", #key))
+                })?,
+            );
+        })
+    });
+    quote! { #( #inserts )* }
+}
+
+/// Returns expression converting `value: &MValue` to struct or variant at `path`
+fn fields_from(
+    fields: &Fields,
+    rename_all: &Option<String>,
+    krate: &syn::Ident,
+    path: TokenStream2,
+    type_name: &str,
+) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let convert = named_fields_from_dict(named, rename_all, krate, path, type_name);
+            quote! {
+                let #krate::__internal::MValue::Dict(dict) = value else {
+                    #krate::__internal::anyhow::bail!(
+                        "Expected mvalue of type: {} (dict), received: {value:?}", #type_name
+                    );
+                };
+                #convert
+            }
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => quote! {
+            Ok(#path(#krate::__internal::FromMValue::from_mvalue(value)?))
+        },
+        Fields::Unnamed(unnamed) => {
+            let mut index = 0_usize;
+            let values = unnamed.unnamed.iter().map(|field| {
+                if FieldAttrs::parse(&field.attrs).skip {
+                    return quote! { Default::default() };
+                }
+                let current = index;
+                index += 1;
+                quote! {
+                    #krate::__internal::FromMValue::from_mvalue(list.get(#current)?).map_err(|error| {
+                        error.context(format!("Failed to convert element: {} of {}", #current, #type_name))
+                    })?
+                }
+            });
+            let values: Vec<_> = values.collect();
+            quote! {
+                let #krate::__internal::MValue::List(list) = value else {
+                    #krate::__internal::anyhow::bail!(
+                        "Expected mvalue of type: {} (list), received: {value:?}", #type_name
+                    );
+                };
+                Ok(#path( #( #values ),* ))
+            }
+        }
+        Fields::Unit => quote! {
+            let #krate::__internal::MValue::None = value else {
+                #krate::__internal::anyhow::bail!(
+                    "Expected mvalue of type: {} (null), received: {value:?}", #type_name
+                );
+            };
+            Ok(#path)
+        },
+    }
+}
+
+/// Returns expression converting `dict: &HashMap<String, MValue>` to struct or variant at `path`
+fn named_fields_from_dict(
+    fields: &syn::FieldsNamed,
+    rename_all: &Option<String>,
+    krate: &syn::Ident,
+    path: TokenStream2,
+    type_name: &str,
+) -> TokenStream2 {
+    let values = fields.named.iter().map(|field| {
+        let attrs = FieldAttrs::parse(&field.attrs);
+        let ident = field.ident.as_ref().unwrap();
+        if attrs.skip {
+            return quote! { #ident: Default::default() };
+        }
+        let key = attrs.name(ident, rename_all);
+
+        // missing value is converted from `MValue::None`, so `Option` fields are optional without `default`
+        let missing = if attrs.default {
+            quote! { Default::default() }
+        } else {
+            quote! {
+                #krate::__internal::FromMValue::from_mvalue(&#krate::__internal::MValue::None)
+                    .map_err(|error| error.context(format!("Missing field: {:?} of {}", #key, #type_name)))?
+            }
+        };
+
+        quote! {
+            #ident: match dict.get(#key) {
+                None | Some(#krate::__internal::MValue::None) => { #missing }
+                Some(value) => #krate::__internal::FromMValue::from_mvalue(value).map_err(|error| {
+                    error.context(format!("Failed to convert field: {:?} of {}", #key, #type_name))
+                })?,
+            }
+        }
+    });
+    quote! {
+        Ok(#path { #( #values ),* })
+    }
+}
+
+fn add_bounds(generics: &syn::Generics, bound: TokenStream2) -> syn::Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote! { #bound });
+    }
+    generics
+}
+
+#[derive(Default)]
+struct ContainerAttrs {
+    rename_all: Option<String>,
+    tag: Option<String>,
+    content: Option<String>,
+    crate_name: Option<String>,
+}
+
+impl ContainerAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut parsed = Self::default();
+        for meta in mvalue_attrs(attrs) {
+            match meta {
+                syn::Meta::NameValue(name_value) => {
+                    let value = lit_str(&name_value);
+                    match name_value_ident(&name_value).as_str() {
+                        "rename_all" => {
+                            // validate it here so error is shown even if there are no fields
+                            convert_case("", &value);
+                            parsed.rename_all = Some(value);
+                        }
+                        "tag" => parsed.tag = Some(value),
+                        "content" => parsed.content = Some(value),
+                        "crate_name" => parsed.crate_name = Some(value),
+                        ident => panic!("unknown mvalue attr: {ident}"),
+                    }
+                }
+                _ => panic!("invalid mvalue attr"),
+            }
+        }
+        if parsed.content.is_some() && parsed.tag.is_none() {
+            panic!("mvalue attr `content` requires `tag`");
+        }
+        parsed
+    }
+
+    fn crate_name(&self) -> syn::Ident {
+        syn::Ident::new(
+            self.crate_name.as_deref().unwrap_or("altv"),
+            Span::call_site(),
+        )
+    }
+}
+
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<String>,
+    rename_all: Option<String>,
+}
+
+impl VariantAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut parsed = Self::default();
+        for meta in mvalue_attrs(attrs) {
+            match meta {
+                syn::Meta::NameValue(name_value) => {
+                    let value = lit_str(&name_value);
+                    match name_value_ident(&name_value).as_str() {
+                        "rename" => parsed.rename = Some(value),
+                        "rename_all" => {
+                            convert_case("", &value);
+                            parsed.rename_all = Some(value);
+                        }
+                        ident => panic!("unknown mvalue attr of variant: {ident}"),
+                    }
+                }
+                _ => panic!("invalid mvalue attr of variant"),
+            }
+        }
+        parsed
+    }
+
+    fn name(&self, ident: &syn::Ident, rename_all: &Option<String>) -> String {
+        renamed(ident, &self.rename, rename_all)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    default: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut parsed = Self::default();
+        for meta in mvalue_attrs(attrs) {
+            match meta {
+                syn::Meta::Path(path) => match path.get_ident().map(|i| i.to_string()).as_deref() {
+                    Some("skip") => parsed.skip = true,
+                    Some("default") => parsed.default = true,
+                    _ => panic!("unknown mvalue attr of field"),
+                },
+                syn::Meta::NameValue(name_value) => match name_value_ident(&name_value).as_str() {
+                    "rename" => parsed.rename = Some(lit_str(&name_value)),
+                    ident => panic!("unknown mvalue attr of field: {ident}"),
+                },
+                _ => panic!("invalid mvalue attr of field"),
+            }
+        }
+        parsed
+    }
+
+    fn name(&self, ident: &syn::Ident, rename_all: &Option<String>) -> String {
+        renamed(ident, &self.rename, rename_all)
+    }
+}
+
+/// Returns contents of all `#[mvalue(...)]` attributes
+fn mvalue_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("mvalue"))
+        .flat_map(|attr| {
+            let Ok(syn::Meta::List(list)) = attr.parse_meta() else {
+                panic!("expected #[mvalue(...)]");
+            };
+            list.nested.into_iter().map(|nested| match nested {
+                syn::NestedMeta::Meta(meta) => meta,
+                syn::NestedMeta::Lit(_) => panic!("invalid mvalue attr"),
+            })
+        })
+        .collect()
+}
+
+fn name_value_ident(name_value: &syn::MetaNameValue) -> String {
+    name_value
+        .path
+        .get_ident()
+        .expect(".path.get_ident()")
+        .to_string()
+}
+
+fn lit_str(name_value: &syn::MetaNameValue) -> String {
+    let syn::Lit::Str(value) = &name_value.lit else {
+        panic!(
+            "value of mvalue attr: {} must be a string",
+            name_value_ident(name_value)
+        );
+    };
+    value.value()
+}
+
+fn renamed(ident: &syn::Ident, rename: &Option<String>, rename_all: &Option<String>) -> String {
+    // raw identifiers such as `r#type`
+    let ident = ident.unraw().to_string();
+
+    match (rename, rename_all) {
+        (Some(rename), _) => rename.clone(),
+        (None, Some(case)) => convert_case(&ident, case),
+        (None, None) => ident,
+    }
+}
+
+/// Converts snake_case (field) or PascalCase (variant) identifier to another case
+fn convert_case(ident: &str, case: &str) -> String {
+    let mut words: Vec<String> = vec![];
+    for part in ident.split('_').filter(|part| !part.is_empty()) {
+        let mut word = String::new();
+        for char in part.chars() {
+            if char.is_uppercase() && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.extend(char.to_lowercase());
+        }
+        words.push(word);
+    }
+
+    let capitalize = |word: &String| {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+            .unwrap_or_default()
+    };
+
+    match case {
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                if index == 0 {
+                    word.clone()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        "PascalCase" => words.iter().map(capitalize).collect(),
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+        "kebab-case" => words.join("-"),
+        "lowercase" => words.concat(),
+        "UPPERCASE" => words.concat().to_uppercase(),
+        case => panic!("unknown rename_all case: {case:?}"),
+    }
+}
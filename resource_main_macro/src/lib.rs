@@ -1,8 +1,13 @@
 #![allow(clippy::needless_doctest_main)]
 
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse::Parser, ItemFn};
 
 // copy-pasted from tokio
@@ -53,24 +58,9 @@ pub fn resource_main_func(args: TokenStream, input: TokenStream) -> TokenStream
     for arg in args {
         match arg {
             syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
-                let ident = name_value
-                    .path
-                    .get_ident()
-                    .expect(".path.get_ident()")
-                    .to_string()
-                    .to_lowercase();
-                match ident.as_str() {
+                match name_value_ident(&name_value).as_str() {
                     "crate_name" => {
-                        if let syn::Lit::Str(s) = name_value.lit {
-                            let path = s.parse::<syn::Path>().expect("s.parse::<syn::Path>()");
-                            let ident = path
-                                .get_ident()
-                                .cloned()
-                                .expect("path.get_ident().cloned()");
-                            crate_name = ident.to_string();
-                        } else {
-                            panic!("crate attr is not syn::Lit::Str");
-                        }
+                        crate_name = parse_crate_name(name_value);
                     }
                     ident => panic!("unknown arg: {ident}"),
                 }
@@ -110,3 +100,266 @@ pub fn resource_main_func(args: TokenStream, input: TokenStream) -> TokenStream
     }
     .into()
 }
+
+/// Declares function that will be called when resource is stopping.
+///
+/// It's called before pending timers are dropped and base objects of this resource are destroyed,
+/// so it's the last place where they can be used (for example, to save something to a database).
+///
+/// ## Example
+/// ```
+/// #[altv::main]
+/// fn main() {
+///    altv::log!("started");
+/// }
+///
+/// #[altv::stop]
+/// fn stop() {
+///    altv::log!("stopping");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn resource_stop_func(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as ItemFn);
+    let RegistrationArgs {
+        positional,
+        crate_name,
+        named,
+    } = parse_registration_args(args);
+
+    if let Some(name_value) = named.first() {
+        panic!("unknown arg: {}", name_value_ident(name_value));
+    }
+    if !positional.is_empty() {
+        panic!("invalid arg");
+    }
+    let handler = &input.sig.ident;
+
+    registration(
+        &input,
+        &crate_name,
+        "STOP",
+        quote! { #crate_name::__internal::set_stop_hook(#handler); },
+    )
+}
+
+/// Registers function as handler of alt:V or custom event (see `altv::events::on_*` functions),
+/// handler is added right after resource is initialized, before main function is called.
+///
+/// ## Example
+/// ```
+/// #[altv::on(player_connect)]
+/// fn on_connect(event: &altv::events::PlayerConnect) -> altv::VoidResult {
+///     altv::log!("player connected: {}", event.player.name()?);
+///     Ok(())
+/// }
+/// ```
+///
+/// `crate_name` can be used the same way as in `altv::main`:
+/// `#[my_custom_name::on(player_connect, crate_name = "my_custom_name")]`
+#[proc_macro_attribute]
+pub fn event_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as ItemFn);
+    let RegistrationArgs {
+        positional,
+        crate_name,
+        named,
+    } = parse_registration_args(args);
+
+    if let Some(name_value) = named.first() {
+        panic!("unknown arg: {}", name_value_ident(name_value));
+    }
+    let [syn::NestedMeta::Meta(syn::Meta::Path(event))] = positional.as_slice() else {
+        panic!("expected event name, for example: #[altv::on(player_connect)]");
+    };
+    let event = event.get_ident().expect("event.get_ident()");
+    let event_func = format_ident!("on_{event}");
+    let handler = &input.sig.ident;
+
+    registration(
+        &input,
+        &crate_name,
+        "ON",
+        quote! { #crate_name::events::#event_func(#handler); },
+    )
+}
+
+/// Registers function as handler of client event.
+///
+/// ## Example
+/// ```
+/// #[altv::on_client("some_event")]
+/// fn on_some_event(event: &altv::events::ClientEventContext) {
+///     altv::log!("player: {:?} args: {:?}", event.player, event.args);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn client_event_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as ItemFn);
+    let RegistrationArgs {
+        positional,
+        crate_name,
+        named,
+    } = parse_registration_args(args);
+
+    if let Some(name_value) = named.first() {
+        panic!("unknown arg: {}", name_value_ident(name_value));
+    }
+    let [syn::NestedMeta::Lit(syn::Lit::Str(event))] = positional.as_slice() else {
+        panic!("expected event name string, for example: #[altv::on_client(\"some_event\")]");
+    };
+    let handler = &input.sig.ident;
+
+    registration(
+        &input,
+        &crate_name,
+        "ON_CLIENT",
+        quote! { #crate_name::events::on_client(#event, #handler); },
+    )
+}
+
+/// Registers function as chat command (see `altv::commands`).
+///
+/// ## Example
+/// ```
+/// #[altv::command("tp", alias = "teleport", permission = "admin", cooldown_ms = 5000)]
+/// fn tp(ctx: &altv::commands::CommandContext) -> altv::VoidResult {
+///     let target: altv::PlayerContainer = ctx.arg(0)?;
+///     ctx.player.set_pos(target.pos()?)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn command_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as ItemFn);
+    let RegistrationArgs {
+        positional,
+        crate_name,
+        named,
+    } = parse_registration_args(args);
+
+    let [syn::NestedMeta::Lit(syn::Lit::Str(command))] = positional.as_slice() else {
+        panic!("expected command name string, for example: #[altv::command(\"tp\")]");
+    };
+
+    let mut aliases = vec![];
+    let mut permission = quote! { None };
+    let mut cooldown = quote! { None };
+
+    for name_value in named {
+        match (name_value_ident(&name_value).as_str(), name_value.lit) {
+            ("alias", syn::Lit::Str(alias)) => aliases.push(alias),
+            ("permission", syn::Lit::Str(value)) => {
+                permission = quote! { Some(#value.to_string()) };
+            }
+            ("cooldown_ms", syn::Lit::Int(value)) => {
+                cooldown = quote! { Some(std::time::Duration::from_millis(#value)) };
+            }
+            (ident, _) => panic!("unknown arg or invalid value of: {ident}"),
+        }
+    }
+
+    let handler = &input.sig.ident;
+
+    registration(
+        &input,
+        &crate_name,
+        "COMMAND",
+        quote! {
+            #crate_name::commands::add(
+                #command,
+                #crate_name::commands::CommandOptions {
+                    aliases: vec![#( #aliases.to_string() ),*],
+                    permission: #permission,
+                    cooldown: #cooldown,
+                },
+                #handler,
+            );
+        },
+    )
+}
+
+struct RegistrationArgs {
+    positional: Vec<syn::NestedMeta>,
+    crate_name: syn::Ident,
+    named: Vec<syn::MetaNameValue>,
+}
+
+fn parse_registration_args(args: TokenStream) -> RegistrationArgs {
+    let args = AttributeArgs::parse_terminated.parse(args).unwrap();
+
+    let mut positional = vec![];
+    let mut crate_name = String::from("altv");
+    let mut named = vec![];
+
+    for arg in args {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+                if name_value_ident(&name_value) == "crate_name" {
+                    crate_name = parse_crate_name(name_value);
+                } else {
+                    named.push(name_value);
+                }
+            }
+            arg => positional.push(arg),
+        }
+    }
+
+    RegistrationArgs {
+        positional,
+        crate_name: syn::Ident::new(&crate_name, Span::call_site()),
+        named,
+    }
+}
+
+/// Keeps the function as is and adds static which will be collected by linker
+/// into `__internal::REGISTRY` and called in `__internal::init`
+fn registration(
+    input: &ItemFn,
+    crate_name: &syn::Ident,
+    kind: &str,
+    register: proc_macro2::TokenStream,
+) -> TokenStream {
+    // one function can have multiple registration attributes (e.g. `#[altv::on("a")] #[altv::on("b")]`),
+    // so name of static also contains kind of attribute and hash of its args
+    let mut hasher = DefaultHasher::new();
+    register.to_string().hash(&mut hasher);
+    let static_ident = format_ident!(
+        "__ALTV_REGISTER_{}_{}_{:x}",
+        input.sig.ident.to_string().to_uppercase(),
+        kind,
+        hasher.finish()
+    );
+
+    quote! {
+        #input
+
+        #[#crate_name::__internal::linkme::distributed_slice(#crate_name::__internal::REGISTRY)]
+        #[linkme(crate = #crate_name::__internal::linkme)]
+        static #static_ident: fn() = || {
+            #register
+        };
+    }
+    .into()
+}
+
+fn name_value_ident(name_value: &syn::MetaNameValue) -> String {
+    name_value
+        .path
+        .get_ident()
+        .expect(".path.get_ident()")
+        .to_string()
+        .to_lowercase()
+}
+
+fn parse_crate_name(name_value: syn::MetaNameValue) -> String {
+    if let syn::Lit::Str(s) = name_value.lit {
+        let path = s.parse::<syn::Path>().expect("s.parse::<syn::Path>()");
+        let ident = path
+            .get_ident()
+            .cloned()
+            .expect("path.get_ident().cloned()");
+        ident.to_string()
+    } else {
+        panic!("crate attr is not syn::Lit::Str");
+    }
+}